@@ -0,0 +1,229 @@
+use crossterm as ct;
+
+use crate::error::FtuiError;
+use crate::error::FtuiResult;
+use crate::renderer::Renderer;
+use crate::util::Colors;
+use crate::util::RenderableMut;
+
+const WEEKDAYS: &str = "Su Mo Tu We Th Fr Sa";
+const MONTHS: [&str; 12] = [
+    "January", "February", "March", "April", "May", "June", "July",
+    "August", "September", "October", "November", "December",
+];
+/// The width of the grid: seven 2 column cells with a space between them.
+const GRID_WIDTH: usize = 20;
+
+/// A UI component showing a month as a grid of days, with one selected day
+/// that can be moved by day, week or month. Like a `Legend`, a `Calendar` is
+/// not part of a container; render it with `Renderer::render`.
+///
+/// # Usage
+/// ```text
+///     March 2026
+/// Su Mo Tu We Th Fr Sa
+///  1  2  3  4  5  6  7
+///  8  9 10 11 12 13 14
+/// ...
+/// ```
+///
+/// # Example
+/// ```rust
+/// let mut calendar = Calendar::new(2026, 3, 14)?;
+///
+/// if let Some(code) = key()? {
+///     calendar.handle_key(code);
+/// }
+///
+/// renderer.render(&mut calendar)?;
+/// let (year, month, day) = calendar.selected();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Calendar {
+    year: i32,
+    month: u8,
+    day: u8,
+    line: u16,
+    highlight: Colors,
+}
+
+impl Calendar {
+    /// Constructs a new `Calendar` with the given date selected.
+    ///
+    /// # Parameters
+    /// - `year`: The year.
+    /// - `month`: The month, from 1 to 12.
+    /// - `day`: The day of the month, starting at 1.
+    ///
+    /// # Returns
+    /// - `Ok(Calendar)`: A new `Calendar` instance.
+    /// - `Err(FtuiError)`: Returns `FtuiError::CalendarInvalidDate` if the
+    ///   date does not exist.
+    pub fn new(year: i32, month: u8, day: u8) -> FtuiResult<Self> {
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return Err(FtuiError::CalendarInvalidDate);
+        }
+
+        Ok(Self {
+            year,
+            month,
+            day,
+            line: 0,
+            highlight: Colors::CyanBack,
+        })
+    }
+
+    /// Sets the line the `Calendar` starts at.
+    pub fn line(mut self, line: u16) -> Self {
+        self.line = line;
+        self
+    }
+
+    /// Sets the background color of the selected day. Defaults to
+    /// `Colors::CyanBack`.
+    pub fn highlight(mut self, color: Colors) -> Self {
+        self.highlight = color;
+        self
+    }
+
+    /// Returns the selected date as `(year, month, day)`.
+    #[inline]
+    pub fn selected(&self) -> (i32, u8, u8) {
+        (self.year, self.month, self.day)
+    }
+
+    /// Moves the selection by `days`, crossing into other months and years
+    /// as needed. The selection stops at the first and last day an `i32` year
+    /// can reach.
+    pub fn move_days(&mut self, days: i32) {
+        let mut remaining = days;
+
+        while remaining > 0 {
+            if self.day < days_in_month(self.year, self.month) {
+                self.day += 1;
+            } else if (self.year, self.month) == (i32::MAX, 12) {
+                break;
+            } else {
+                self.shift_month(1);
+                self.day = 1;
+            }
+            remaining -= 1;
+        }
+
+        while remaining < 0 {
+            if self.day > 1 {
+                self.day -= 1;
+            } else if (self.year, self.month) == (i32::MIN, 1) {
+                break;
+            } else {
+                self.shift_month(-1);
+                self.day = days_in_month(self.year, self.month);
+            }
+            remaining += 1;
+        }
+    }
+
+    /// Moves the selection by `months`. The day is clamped to the length of
+    /// the new month, so January 31 moves to the last day of February.
+    pub fn move_months(&mut self, months: i32) {
+        self.shift_month(months);
+        self.day = self.day.min(days_in_month(self.year, self.month));
+    }
+
+    fn shift_month(&mut self, months: i32) {
+        // Counted in i64 so years near the limits do not overflow, then
+        // held at the first and last month an i32 year can reach.
+        let index = (self.year as i64 * 12 + (self.month as i64 - 1) + months as i64)
+            .clamp(i32::MIN as i64 * 12, i32::MAX as i64 * 12 + 11);
+
+        self.year = index.div_euclid(12) as i32;
+        self.month = (index.rem_euclid(12) + 1) as u8;
+    }
+
+    /// Moves the selection for a key press: the arrow keys move by a day or a
+    /// week, and Page Up / Page Down switch months.
+    ///
+    /// # Returns
+    /// - `true`: The key moved the selection.
+    /// - `false`: The key is not used by the `Calendar`.
+    pub fn handle_key(&mut self, code: ct::event::KeyCode) -> bool {
+        match code {
+            ct::event::KeyCode::Left => self.move_days(-1),
+            ct::event::KeyCode::Right => self.move_days(1),
+            ct::event::KeyCode::Up => self.move_days(-7),
+            ct::event::KeyCode::Down => self.move_days(7),
+            ct::event::KeyCode::PageUp => self.move_months(-1),
+            ct::event::KeyCode::PageDown => self.move_months(1),
+            _ => return false,
+        }
+
+        true
+    }
+
+    /// Returns the number of lines the current month takes: the title, the
+    /// weekday names and one line per week.
+    pub fn height(&self) -> u16 {
+        let cells = weekday(self.year, self.month, 1) as u16
+            + days_in_month(self.year, self.month) as u16;
+
+        2 + cells.div_ceil(7)
+    }
+}
+
+impl RenderableMut<Renderer> for Calendar {
+    fn render(&mut self, renderer: &mut Renderer) -> FtuiResult<()> {
+        let (width, height) = renderer.get_dimensions();
+
+        if (width as usize) < GRID_WIDTH ||
+            self.line as usize + self.height() as usize > height as usize
+        {
+            return Err(FtuiError::RendererContainerTooBig);
+        }
+
+        let title = format!("{} {}", MONTHS[self.month as usize - 1], self.year);
+        let title_pos = GRID_WIDTH.saturating_sub(title.chars().count()) / 2;
+        let first = weekday(self.year, self.month, 1) as usize;
+        let line = self.line as usize;
+
+        renderer.line_mut(line).edit(&title, title_pos as u16);
+        renderer.line_mut(line + 1).edit(WEEKDAYS, 0);
+
+        for day in 1..=days_in_month(self.year, self.month) {
+            let cell = first + day as usize - 1;
+            let column = (cell % 7) * 3;
+            let row = renderer.line_mut(line + 2 + cell / 7);
+
+            row.edit(&format!("{:>2}", day), column as u16);
+
+            if day == self.day {
+                row.add_span(column, 2, vec![self.highlight.to_ansi().into()]);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Returns the day of the week, 0 being Sunday (Sakamoto's method).
+fn weekday(year: i32, month: u8, day: u8) -> u8 {
+    const OFFSETS: [i64; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+
+    let year = if month < 3 { year as i64 - 1 } else { year as i64 };
+    let days = year + year.div_euclid(4) - year.div_euclid(100) + year.div_euclid(400)
+        + OFFSETS[month as usize - 1] + day as i64;
+
+    days.rem_euclid(7) as u8
+}
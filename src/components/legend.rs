@@ -0,0 +1,185 @@
+use crate::components::TextFlags;
+use crate::error::FtuiError;
+use crate::error::FtuiResult;
+use crate::renderer::Renderer;
+use crate::util::Colors;
+use crate::util::RenderableMut;
+
+const DEFAULT_SWATCH: char = '█';
+const DEFAULT_GAP: usize = 3;
+
+/// An `enum` describing how the entries of a `Legend` are arranged.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LegendLayout {
+    /// Entries are placed side by side, wrapping onto the next line when
+    /// the `Renderer` is not wide enough.
+    ///
+    /// `█ Label   █ Label`
+    #[default]
+    Row,
+
+    /// Each entry is placed on its own line.
+    ///
+    /// `█ Label`  
+    /// `█ Label`
+    Column,
+}
+
+/// A UI component showing colored swatches next to labels, typically used as
+/// the legend of a chart. Unlike other components, a `Legend` is not part of
+/// a container; render it with `Renderer::render` after the container.
+///
+/// # Usage
+/// `█ Income   █ Expenses   █ Savings`
+///
+/// # Example
+/// ```rust
+/// let mut legend = Legend::new(LegendLayout::Row)
+///     .line(10)
+///     .entry(Colors::GreenFore, "Income")
+///     .entry(Colors::RedFore, "Expenses");
+///
+/// renderer.render(&mut container)?;
+/// renderer.render(&mut legend)?;
+/// renderer.flush()?;
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Legend {
+    entries: Vec<(Colors, String, usize)>,
+    layout: LegendLayout,
+    line: u16,
+    swatch: char,
+    gap: usize,
+    label_flags: Option<TextFlags>,
+}
+
+impl Legend {
+    /// Constructs a new empty `Legend` starting at the first line.
+    ///
+    /// # Parameters
+    /// - `layout`: How the entries are arranged.
+    pub fn new(layout: LegendLayout) -> Self {
+        Legend {
+            entries: vec![],
+            layout,
+            line: 0,
+            swatch: DEFAULT_SWATCH,
+            gap: DEFAULT_GAP,
+            label_flags: None,
+        }
+    }
+
+    /// Adds an entry, see `add`.
+    pub fn entry(mut self, color: Colors, label: impl ToString) -> Self {
+        self.add(color, label);
+        self
+    }
+
+    /// Sets the line the `Legend` starts at.
+    pub fn line(mut self, line: u16) -> Self {
+        self.line = line;
+        self
+    }
+
+    /// Sets the character used for the swatches. Defaults to `█`.
+    pub fn swatch(mut self, swatch: char) -> Self {
+        self.swatch = swatch;
+        self
+    }
+
+    /// Sets the number of spaces between entries in a `LegendLayout::Row`.
+    /// Defaults to 3.
+    pub fn gap(mut self, gap: usize) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Sets the `TextFlags` used for the labels. Alignment flags are ignored.
+    ///
+    /// # Returns
+    /// - `Ok(Legend)`: Returns `self`.
+    /// - `Err(FtuiError)`: Returns an error.
+    pub fn label_flags(mut self, flags: TextFlags) -> FtuiResult<Self> {
+        flags.ensure_compatibility()?;
+        self.label_flags = Some(flags);
+        Ok(self)
+    }
+
+    /// Adds an entry with a swatch of the given color.
+    ///
+    /// # Parameters
+    /// - `color`: The color of the swatch.
+    /// - `label`: The label shown next to the swatch.
+    pub fn add(&mut self, color: Colors, label: impl ToString) {
+        let label = label.to_string();
+        // Measured in cells, one per character like the `Renderer` writes.
+        let len = label.chars().count();
+
+        self.entries.push((color, label, len));
+    }
+
+    /// Removes all entries.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns the position (column, line offset) of every entry when laid
+    /// out in `width` columns.
+    fn positions(&self, width: usize) -> FtuiResult<Vec<(usize, usize)>> {
+        let mut positions = Vec::with_capacity(self.entries.len());
+        let (mut x, mut y) = (0, 0);
+
+        for (_, _, len) in &self.entries {
+            // The swatch and the space after it.
+            let entry_width = len + 2;
+
+            if entry_width > width {
+                return Err(FtuiError::RendererContainerTooBig);
+            }
+
+            if self.layout == LegendLayout::Column && !positions.is_empty() {
+                y += 1;
+            } else if x != 0 && x + self.gap + entry_width > width {
+                (x, y) = (0, y + 1);
+            } else if x != 0 {
+                x += self.gap;
+            }
+
+            positions.push((x, y));
+
+            if self.layout == LegendLayout::Row {
+                x += entry_width;
+            }
+        }
+
+        Ok(positions)
+    }
+}
+
+impl RenderableMut<Renderer> for Legend {
+    fn render(&mut self, renderer: &mut Renderer) -> FtuiResult<()> {
+        let (width, height) = renderer.get_dimensions();
+        let positions = self.positions(width as usize)?;
+        let label_ansi = self.label_flags.map(|flags| flags.resolve_ansi());
+
+        if let Some((_, y)) = positions.last() &&
+            self.line as usize + y >= height as usize
+        {
+            return Err(FtuiError::RendererContainerTooBig);
+        }
+
+        for ((color, label, len), (x, y)) in self.entries.iter().zip(positions) {
+            let line = renderer.line_mut(self.line as usize + y);
+
+            line.edit_iter([self.swatch, ' '].into_iter().chain(label.chars()), x as u16);
+            line.add_span(x, 1, vec![color.to_ansi().into()]);
+
+            if let Some(ansi) = &label_ansi {
+                line.add_span(x + 2, *len, ansi.clone());
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -2,14 +2,25 @@
 pub(crate) mod option;
 pub use option::Option;
 pub use option::OptionsManager;
+pub use option::SelectionStyle;
 
 /// A UI component representing a text element in a `Container`.
 pub(crate) mod text;
 pub use text::Text;
 pub use text::TextFlags;
+pub use text::ControlChars;
 pub use text::TextsManager;
 
 /// A UI component that acts as a separator typically a horizontal line.
 pub(crate) mod seperator;
 pub(crate) use seperator::Separator;
 pub use seperator::SeparatorStyle;
+
+/// A UI component showing colored swatches next to labels.
+pub(crate) mod legend;
+pub use legend::Legend;
+pub use legend::LegendLayout;
+
+/// A UI component showing a month as a grid of selectable days.
+pub(crate) mod calendar;
+pub use calendar::Calendar;
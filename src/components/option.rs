@@ -1,9 +1,18 @@
+use std::any::Any;
+use std::borrow::Cow;
+use std::sync::Arc;
+
 use unicode_segmentation::UnicodeSegmentation;
 
+use crate::containers::WordWrap;
+use crate::containers::WrapStrategy;
 use crate::renderer::Renderer;
 use crate::util::id::GeneratedId;
+use crate::util::id::IdIndex;
+use crate::util::ansi;
 use crate::util::Colors;
-use crate::util::Renderable;
+use crate::util::OverflowPolicy;
+use crate::error::FtuiError;
 use crate::error::FtuiResult;
 
 /// A UI component representing an interactive option in a `Container`. 
@@ -26,8 +35,29 @@ pub struct Option {
     id: GeneratedId,
     selc_on: bool,
     is_selc: bool,
+    disabled: bool,
+    userdata: std::option::Option<Userdata>,
+}
+
+/// Arbitrary data attached to an `Option`. Clones of an `Option` share the
+/// same data, and two `Option`s are only equal if they share it.
+#[derive(Clone)]
+struct Userdata(Arc<dyn Any + Send + Sync>);
+
+impl std::fmt::Debug for Userdata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Userdata(..)")
+    }
 }
 
+impl PartialEq for Userdata {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for Userdata {}
+
 impl Option {
     /// Creates a new `Option` with the specified label and callback.
     ///
@@ -44,6 +74,8 @@ impl Option {
             line: 0,
             selc_on: false,
             is_selc: false,
+            disabled: false,
+            userdata: None,
         }
     }
 
@@ -110,6 +142,79 @@ impl Option {
         self.is_selc = value;
     }
 
+    /// Returns whether the `Option` is disabled, see `set_disabled`.
+    #[inline]
+    pub fn disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Disables or enables the `Option`. A disabled `Option` is still shown,
+    /// but the `Selector` skips over it and it cannot be selected. Useful for
+    /// section labels between options.
+    ///
+    /// # Notes
+    /// - Disabling the `Option` the `Selector` is on does not move the
+    ///   `Selector`, but `selector_select` does nothing until it moves.
+    ///
+    /// # Parameters
+    /// - `disabled`: Whether the `Option` is disabled.
+    ///
+    /// # Example
+    /// ```rust
+    /// container.options_mut().query_mut(save_id).unwrap()
+    ///     .set_disabled(!has_changes);
+    /// ```
+    #[inline]
+    pub fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+    }
+
+    /// Attaches arbitrary data to the `Option`, replacing any previous data.
+    /// Useful for acting on a selection without keeping a separate lookup
+    /// table from IDs to data.
+    ///
+    /// # Notes
+    /// - The data must be `Send` and `Sync`, so containers can still be moved
+    ///   to other threads.
+    ///
+    /// # Parameters
+    /// - `data`: The data to attach.
+    ///
+    /// # Example
+    /// ```rust
+    /// enum Action { Open, Quit }
+    ///
+    /// container.options_mut().query_mut(quit_id).unwrap()
+    ///     .set_userdata(Action::Quit);
+    /// ```
+    pub fn set_userdata<T: Any + Send + Sync>(&mut self, data: T) {
+        self.userdata = Some(Userdata(Arc::new(data)));
+    }
+
+    /// Returns the data attached to the `Option`.
+    ///
+    /// # Returns
+    /// - `Some(&T)`: The attached data, if it is of type `T`.
+    /// - `None`: No data is attached, or it is of a different type.
+    ///
+    /// # Example
+    /// ```rust
+    /// if option.is_selc() {
+    ///     match option.userdata::<Action>() {
+    ///         Some(Action::Quit) => todo!(),
+    ///         ...
+    ///     }
+    /// }
+    /// ```
+    pub fn userdata<T: Any>(&self) -> std::option::Option<&T> {
+        self.userdata.as_ref()?.0.downcast_ref::<T>()
+    }
+
+    /// Removes the data attached to the `Option`, if any.
+    pub fn clear_userdata(&mut self) {
+        self.userdata = None;
+    }
+
     pub(crate) fn id(&self) -> GeneratedId {
         self.id
     }
@@ -119,31 +224,230 @@ impl Option {
     }
 }
  
+/// The marker drawn before the selected `Option` with `SelectionStyle::Marker`.
+const SELECTION_MARKER: &str = "> ";
+
+/// How far the rows after the first of a wrapped `Option` are indented.
+const WRAP_INDENT: usize = 2;
+
+/// An `enum` representing how the selected `Option` is shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionStyle {
+    /// The line is highlighted with a color.
+    Color(Colors),
+
+    /// The line is shown in reverse video, which follows the terminal's own
+    /// colors.
+    Reverse,
+
+    /// A `>` marker is drawn before the label, without any color. Every label
+    /// is indented to make room for it.
+    ///
+    /// `> Selected`  
+    /// `  Other`
+    Marker,
+}
+
+impl Default for SelectionStyle {
+    fn default() -> Self {
+        SelectionStyle::Color(Colors::CyanBack)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct OptionsManager {
     components: Vec<Option>,
-    selector_on: usize,
-    highlight: Colors, 
+    index: IdIndex,
+    selector_on: std::option::Option<usize>,
+    default_selection: bool,
+    selection_style: SelectionStyle,
+    decoration: std::option::Option<(String, String)>,
+    wrap: bool,
 }
 
 impl OptionsManager {
     pub(crate) fn new() -> Self {
         Self {
             components: Vec::new(),
-            selector_on: 0,
-            highlight: Colors::CyanBack,
+            index: IdIndex::default(),
+            selector_on: None,
+            default_selection: true,
+            selection_style: SelectionStyle::default(),
+            decoration: None,
+            wrap: false,
         }
     }
 
+    /// Adds an `Option`. Unless the default selection is disabled, the first
+    /// enabled `Option` added starts with the `Selector` on it.
     pub(crate) fn add(&mut self, component: Option) {
+        let disabled = component.disabled();
+
+        self.index.insert(component.id(), self.components.len());
         self.components.push(component);
+
+        if self.default_selection && self.selector_on.is_none() && !disabled {
+            self.move_selector(self.components.len() - 1);
+        }
+    }
+
+    /// Enables `O(1)` lookups by ID.
+    pub(crate) fn enable_index(&mut self) {
+        self.index.enable(self.components.iter().map(|option| option.id()));
+    }
+
+    /// Removes every `Option` and the `Selector` with them. The next `Option`
+    /// added is selected again unless the default selection is disabled.
+    pub(crate) fn clear(&mut self) {
+        self.components.clear();
+        self.index.rebuild(std::iter::empty());
+        self.selector_on = None;
+    }
+
+    /// Inserts an `Option` at `index`, or at the end if `index` is past it,
+    /// keeping the `Selector` on the `Option` it was on.
+    ///
+    /// # Returns
+    /// `usize`: The index the `Option` was inserted at.
+    pub(crate) fn insert_at(&mut self, index: usize, component: Option) -> usize {
+        let index = index.min(self.components.len());
+        let disabled = component.disabled();
+
+        self.components.insert(index, component);
+        self.index.rebuild(self.components.iter().map(|option| option.id()));
+
+        match self.selector_on {
+            Some(selected) if selected >= index => self.selector_on = Some(selected + 1),
+            None if self.default_selection && !disabled => self.move_selector(index),
+            _ => {},
+        }
+
+        index
+    }
+
+    /// Removes the `Option` with the given ID. If the `Selector` was on it,
+    /// it moves to the next enabled `Option`, or the previous one at the end.
+    ///
+    /// # Returns
+    /// - `Ok(usize)`: The index the `Option` was at.
+    /// - `Err(FtuiError)`: No `Option` with the given ID exists.
+    pub(crate) fn remove(&mut self, id: GeneratedId) -> FtuiResult<usize> {
+        let index = self.find(id).ok_or(FtuiError::ContainerNoComponentById)?;
+
+        self.components.remove(index);
+        self.index.rebuild(self.components.iter().map(|option| option.id()));
+
+        match self.selector_on {
+            Some(selected) if selected == index => {
+                self.selector_on = None;
+
+                if !self.move_to_enabled(index..self.components.len()) {
+                    self.move_to_enabled((0..index).rev());
+                }
+            },
+            Some(selected) if selected > index => self.selector_on = Some(selected - 1),
+            _ => {},
+        }
+
+        Ok(index)
+    }
+
+    fn find(&self, id: GeneratedId) -> std::option::Option<usize> {
+        self.index.find(id, || self.components.iter().position(|option| option.id() == id))
+    }
+
+    /// Stops the first `Option` from being selected by default. Nothing is
+    /// selected until the `Selector` is first moved.
+    pub(crate) fn disable_default_selection(&mut self) {
+        self.default_selection = false;
+
+        if let Some(index) = self.selector_on.take() {
+            self.components[index].set_selc_on(false);
+        }
+    }
+
+    /// Moves the `Selector` onto the `Option` at `index`.
+    fn move_selector(&mut self, index: usize) {
+        if let Some(current) = self.selector_on {
+            self.components[current].set_selc_on(false);
+        }
+
+        self.selector_on = Some(index);
+        self.components[index].set_selc_on(true);
     }
 
-    pub(crate) fn set_highlight(&mut self, color: Colors) {
-        self.highlight = color;
+    /// Selects the first enabled `Option`, used when navigating with nothing
+    /// selected.
+    fn select_first(&mut self) -> bool {
+        self.move_to_enabled(0..self.components.len())
     }
 
-    /// Query an `Option` component by its ID (`O(n)` lookup).
+    /// Moves the `Selector` onto the first enabled `Option` among `indices`.
+    ///
+    /// # Returns
+    /// - `true`: The `Selector` was moved.
+    /// - `false`: Every `Option` among `indices` is disabled.
+    fn move_to_enabled(&mut self, mut indices: impl Iterator<Item = usize>) -> bool {
+        match indices.find(|&i| !self.components[i].disabled()) {
+            Some(index) => {
+                self.move_selector(index);
+                true
+            },
+            None => false,
+        }
+    }
+
+    pub(crate) fn set_wrap(&mut self, wrap: bool) {
+        self.wrap = wrap;
+    }
+
+    pub(crate) fn set_selection_style(&mut self, style: SelectionStyle) {
+        self.selection_style = style;
+    }
+
+    /// Sets decorations drawn at the left and right edges of the selected
+    /// `Option`'s line, on top of the highlight.
+    ///
+    /// `►  Option                ◄`
+    ///
+    /// # Notes
+    /// - Every label is indented past the left decoration so labels stay
+    ///   lined up and are never overwritten.
+    /// - The right decoration is clipped when the label leaves no room for it.
+    ///
+    /// # Parameters
+    /// - `left`: The decoration drawn at the left edge.
+    /// - `right`: The decoration drawn at the right edge.
+    ///
+    /// # Example
+    /// ```rust
+    /// container.options_mut().set_selection_decoration("►", "◄");
+    /// ```
+    pub fn set_selection_decoration(&mut self, left: &str, right: &str) {
+        self.decoration = Some((left.to_string(), right.to_string()));
+    }
+
+    /// Removes the decorations set with `set_selection_decoration`.
+    pub fn clear_selection_decoration(&mut self) {
+        self.decoration = None;
+    }
+
+    /// Moves the `Selector` onto the `Option` with the given ID.
+    ///
+    /// # Returns
+    /// - `true`: The `Selector` was moved.
+    /// - `false`: No `Option` with the given ID exists.
+    pub(crate) fn set_selector_on(&mut self, id: GeneratedId) -> bool {
+        let index = match self.find(id) {
+            Some(index) => index,
+            None => return false,
+        };
+
+        self.move_selector(index);
+        true
+    }
+
+    /// Query an `Option` component by its ID (`O(n)` lookup, `O(1)` when indexed).
     ///
     /// # Parameters
     /// - `id`: The ID of the `Option` component to query.
@@ -166,10 +470,10 @@ impl OptionsManager {
     /// ```
     #[inline]
     pub fn query(&self, id: GeneratedId) -> std::option::Option<&Option> {
-        self.components.iter().find(|option| option.id() == id)
+        self.find(id).map(|index| &self.components[index])
     }
 
-    /// Query an `Option` component by its ID (`O(n)` lookup).
+    /// Query an `Option` component by its ID (`O(n)` lookup, `O(1)` when indexed).
     ///
     /// # Parameters
     /// - `id`: The ID of the `Option` component to query.
@@ -192,10 +496,21 @@ impl OptionsManager {
     /// ```
     #[inline]
     pub fn query_mut(&mut self, id: GeneratedId) -> std::option::Option<&mut Option> {
-        self.components.iter_mut().find(|option| option.id() == id)
+        self.find(id).map(|index| &mut self.components[index])
     }
 
-    /// Attempts to move the `Selector` up by one position, if possible.
+    /// Returns the `Option` the `Selector` is on.
+    ///
+    /// # Returns
+    /// - `Some(&Option)`: A reference to the selected `Option` component.
+    /// - `None`: No `Option` is selected, or there are none.
+    #[inline]
+    pub fn selected(&self) -> std::option::Option<&Option> {
+        self.selector_on.map(|index| &self.components[index])
+    }
+
+    /// Attempts to move the `Selector` up to the previous enabled `Option`,
+    /// if possible.
     ///
     /// # Returns
     /// - `Ok(true)`: The selector moved up successfully.
@@ -215,19 +530,14 @@ impl OptionsManager {
     /// assert_eq!(container.selector_up()?, false);
     /// ```
     pub fn selector_up(&mut self) -> bool {
-        if self.selector_on == 0 {
-            return false;
+        match self.selector_on {
+            None => self.select_first(),
+            Some(index) => self.move_to_enabled((0..index).rev()),
         }
-
-        // move the selector up
-        self.components[self.selector_on].set_selc_on(false);
-        self.selector_on -= 1;
-        self.components[self.selector_on].set_selc_on(true);
-
-        true
     }
 
-    /// Attempts to move the `Selector` down by one position, if possible.
+    /// Attempts to move the `Selector` down to the next enabled `Option`, if
+    /// possible.
     ///
     /// # Returns
     /// - `Ok(true)`: The selector moved down successfully.
@@ -247,24 +557,19 @@ impl OptionsManager {
     /// assert_eq!(container.selector_up()?, true);
     /// ```
     pub fn selector_down(&mut self) -> bool {
-        if self.selector_on == self.components.len() - 1 {
-            return false;
+        match self.selector_on {
+            None => self.select_first(),
+            Some(index) => self.move_to_enabled(index + 1..self.components.len()),
         }
-
-        // move selector down
-        self.components[self.selector_on].set_selc_on(false);
-        self.selector_on += 1;
-        self.components[self.selector_on].set_selc_on(true);
-
-        true
     }
 
     /// Attempts to select the `Option` that the `Selector` is currently on. 
-    /// This operation should always succeed unless an error occurs internally.
+    /// This operation succeeds unless nothing is selected or the `Option` is
+    /// disabled.
     ///
     /// # Returns
-    /// - `Ok(true)`: The selection was successful.
-    /// - `Err(FtuiError)`: An error occurred during selection.
+    /// - `true`: The selection was successful.
+    /// - `false`: Nothing is selected, or the `Option` is disabled.
     ///
     /// # Example
     /// ```rust
@@ -278,33 +583,117 @@ impl OptionsManager {
     /// assert_eq!(container.selector_select()?, true);
     /// ```
     pub fn selector_select(&mut self) -> bool {
-        if self.components.is_empty() {
-            return false;
+        match self.selector_on {
+            Some(index) if self.components[index].disabled() => false,
+            Some(index) => {
+                self.components[index].set_is_selc(true);
+                true
+            },
+            None => false,
         }
-
-        self.components[self.selector_on].set_is_selc(true);
-        true
     }
 
     pub(crate) fn comps(&self) -> &[Option] {
         &self.components
     }
+
+    pub(crate) fn comps_mut(&mut self) -> &mut [Option] {
+        &mut self.components
+    }
 }
 
-impl Renderable<Renderer> for OptionsManager {
-    fn render(&self, renderer: &mut Renderer) -> FtuiResult<()> {
+impl OptionsManager {
+    /// Returns the width of the left decoration and the column labels start at
+    /// in a `Renderer` `width` columns wide.
+    fn indents(&self, width: usize) -> (usize, usize) {
+        let decoration_width = match &self.decoration {
+            Some((left, _)) if !left.is_empty() => (left.chars().count() + 1).min(width),
+            _ => 0,
+        };
+        let indent = match self.selection_style {
+            SelectionStyle::Marker => decoration_width + SELECTION_MARKER.len(),
+            _ => decoration_width,
+        }.min(width);
+
+        (decoration_width, indent)
+    }
+
+    /// Returns the rows the label of `option` takes in a `Renderer` `width`
+    /// columns wide. Only a wrapped label takes more than one row.
+    fn label_rows<'a>(
+        &self, option: &'a Option, width: usize, overflow: OverflowPolicy
+    ) -> FtuiResult<Vec<Cow<'a, str>>> {
+        let available = width - self.indents(width).1;
+
+        if (self.wrap || overflow == OverflowPolicy::Wrap) && option.len() > available {
+            let rows = WordWrap.wrap(option.label(), available.saturating_sub(WRAP_INDENT));
+            Ok(rows.into_iter().map(Cow::Owned).collect())
+        } else {
+            overflow.rows(option.label(), option.len(), available)
+        }
+    }
+
+    /// Returns how many rows every `Option` takes in a `Renderer` `width`
+    /// columns wide. A label that does not fit without wrapping counts as one
+    /// row, the error is left to `render_with`.
+    pub(crate) fn row_counts(&self, width: usize, overflow: OverflowPolicy) -> Vec<u16> {
+        self.comps()
+            .iter()
+            .map(|option| self
+                .label_rows(option, width, overflow)
+                .map_or(1, |rows| rows.len() as u16))
+            .collect()
+    }
+
+    pub(crate) fn render_with(
+        &self, renderer: &mut Renderer, overflow: OverflowPolicy
+    ) -> FtuiResult<()> {
+        let width = renderer.get_dimensions().0 as usize;
+        let (decoration_width, indent) = self.indents(width);
+
         for option in self.comps() {
-            renderer.ensure_label_inbound(option.len())?;
-            
-            let line = &mut renderer.line_mut(option.line() as usize);
+            let rows = self.label_rows(option, width, overflow)?;
+
+            for (i, row) in rows.iter().enumerate() {
+                let line = renderer.line_mut(option.line() as usize + i);
+                let row_indent = if i == 0 { indent } else { indent + WRAP_INDENT };
 
-            line.edit(option.label(), 0);
+                line.edit_iter(row.chars().take(width - row_indent.min(width)), row_indent as u16);
+
+                if option.selc_on() {
+                    match self.selection_style {
+                        SelectionStyle::Color(color) => line.add_ansi(color.to_ansi()),
+                        SelectionStyle::Reverse => line.add_ansi(ansi::ESC_REVERSED),
+                        SelectionStyle::Marker => {},
+                    }
+                }
+            }
+
+            let label = &rows[0];
+            let line = renderer.line_mut(option.line() as usize);
 
             if option.selc_on() {
-                line.add_ansi(self.highlight.to_ansi());
+                if self.selection_style == SelectionStyle::Marker {
+                    line.edit_iter(
+                        SELECTION_MARKER.chars().take(indent - decoration_width),
+                        decoration_width as u16);
+                }
+
+                if let Some((left, right)) = &self.decoration {
+                    let label_end = indent + label.chars().count();
+                    let right_begin = width
+                        .saturating_sub(right.chars().count())
+                        .max(label_end + 1)
+                        .min(width);
+
+                    line.edit_iter(left.chars().take(decoration_width.saturating_sub(1)), 0);
+                    line.edit_iter(
+                        right.chars().take(width - right_begin), right_begin as u16);
+                }
             }
         }
 
         Ok(())
     }
 }
+
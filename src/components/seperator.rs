@@ -32,6 +32,19 @@ pub enum SeparatorStyle {
     Custom(char),
 }
 
+impl SeparatorStyle {
+    /// Returns the glyphs joining a line of this style to a side border of the
+    /// same style, if the style is drawn with box-drawing characters.
+    fn caps(self) -> Option<(char, char)> {
+        match self {
+            SeparatorStyle::Thin => Some(('├', '┤')),
+            SeparatorStyle::Medium => Some(('┣', '┫')),
+            SeparatorStyle::Double => Some(('╠', '╣')),
+            SeparatorStyle::Solid | SeparatorStyle::Custom(_) => None,
+        }
+    }
+}
+
 /// A UI component that acts as a separator typically a horizontal line.
 /// `Separator` components are displayed in the order they are added to a
 /// `Container`. Each `Separator` can have a different style, specified using
@@ -41,6 +54,9 @@ pub enum SeparatorStyle {
 /// # Notes
 /// - A normal separator looks like this: `-------`
 /// - A dotted separator looks like this: `- - - -`
+/// - A capped separator ends in tee glyphs, like this: `├─────┤`
+/// - A contextual separator is only drawn when it sits between other
+///   components, so it never shows up as a lone line at an edge.
 ///
 /// # Usage
 /// The `Separator` component is useful for dividing sections in your terminal UI.
@@ -48,6 +64,8 @@ pub enum SeparatorStyle {
 pub(crate) struct Separator {
     line: u16,
     dotted: bool,
+    contextual: bool,
+    capped: bool,
     style: SeparatorStyle,
 }
 
@@ -60,6 +78,8 @@ impl Separator {
         Separator {
             line: 0,
             dotted: false,
+            contextual: false,
+            capped: false,
             style,
         }
     }
@@ -72,10 +92,37 @@ impl Separator {
         Separator {
             line: 0,
             dotted: true,
+            contextual: false,
+            capped: false,
             style
         }
     }
 
+    /// Ends the `Separator` with tee glyphs matching its style, so it joins
+    /// side borders drawn with the same style. Styles that are not drawn with
+    /// box-drawing characters have no caps.
+    ///
+    /// # Returns
+    /// `Separator`: Returns `self`.
+    pub(crate) fn capped(mut self) -> Self {
+        self.capped = true;
+        self
+    }
+
+    /// Makes the `Separator` contextual, only drawn when there is a component
+    /// both above and below it.
+    ///
+    /// # Returns
+    /// `Separator`: Returns `self`.
+    pub(crate) fn contextual(mut self) -> Self {
+        self.contextual = true;
+        self
+    }
+
+    pub(crate) fn is_contextual(&self) -> bool {
+        self.contextual
+    }
+
     pub(crate) fn set_line(&mut self, line: u16) {
         self.line = line; 
     }
@@ -95,10 +142,18 @@ impl Separator {
 
 #[inline]
 fn apply_correct_separator(renderer: &mut Renderer, separator: &Separator, c: char) {
+    let (width, _) = renderer.get_dimensions();
+    let line = renderer.line_mut(separator.line() as usize);
+
     if separator.is_dotted() {
-        renderer.line_mut(separator.line() as usize).fill_dotted(c);
+        line.fill_dotted(c);
     } else {
-        renderer.line_mut(separator.line() as usize).fill(c);
+        line.fill(c);
+    }
+
+    if separator.capped && width > 0 && let Some((left, right)) = separator.style.caps() {
+        line.edit_iter(std::iter::once(left), 0);
+        line.edit_iter(std::iter::once(right), width - 1);
     }
 }
     
@@ -1,14 +1,37 @@
 use bitflags::bitflags;
-
 use unicode_segmentation::UnicodeSegmentation;
 
 use crate::error::FtuiError;
 use crate::error::FtuiResult;
+use crate::renderer::Line;
 use crate::renderer::Renderer;
+use crate::util;
 use crate::util::ansi;
+use crate::util::ansi::Ansi;
 use crate::util::id::GeneratedId;
+use crate::util::id::IdIndex;
+use crate::util::Color;
+use crate::util::Colors;
+use crate::util::OverflowPolicy;
+use crate::util::Style;
 use crate::util::RenderableMut;
 
+/// The colors `Text::rainbow` cycles through.
+const RAINBOW: [Colors; 6] = [
+    Colors::RedFore,
+    Colors::YellowFore,
+    Colors::GreenFore,
+    Colors::CyanFore,
+    Colors::BlueFore,
+    Colors::MagentaFore,
+];
+
+/// The number of spaces a tab in a label is expanded to.
+const TAB_WIDTH: usize = 4;
+
+/// The character control characters are replaced with by `ControlChars::Replace`.
+const REPLACEMENT_CHAR: char = '\u{FFFD}';
+
 bitflags! {
     /// Flags used to style a `Text` component. Multiple flags can be combined 
     /// using the bitwise OR operator to apply multiple styles simultaneously.
@@ -25,6 +48,9 @@ bitflags! {
         const ALIGN_RIGHT   = 1 << 1;
         /// Centers text horizontally in the renderer.
         const ALIGN_MIDDLE  = 1 << 2;
+        /// Extends the colors and styles of the text across the whole line,
+        /// not just the label. Useful for title bars.
+        const FILL_LINE     = 1 << 3;
 
         // Applies colors to the background of the text instead of foreground.
         const COLOR_BACK    = 1 << 4;
@@ -94,10 +120,11 @@ impl TextFlags {
     pub fn tailwind(styles: &str) -> FtuiResult<TextFlags> {
         let result = styles
             .split_whitespace()
-            .fold(TextFlags::empty(), |acc, style| {
-                acc.union(match style {
+            .try_fold(TextFlags::empty(), |acc, style| {
+                Ok(acc.union(match style {
                     "a-r" => TextFlags::ALIGN_RIGHT,
                     "a-m" => TextFlags::ALIGN_MIDDLE,
+                    "f-l" => TextFlags::FILL_LINE,
                     "c-bg" => TextFlags::COLOR_BACK,
                     "c-b" => TextFlags::COLOR_BLACK,
                     "c-r" => TextFlags::COLOR_RED,
@@ -112,9 +139,9 @@ impl TextFlags {
                     "s-i" => TextFlags::STYLE_ITALIC,
                     "s-u" => TextFlags::STYLE_UNDER,
                     "s-s" => TextFlags::STYLE_STRIKE,
-                    _ => todo!(),
-                })
-            });
+                    _ => return Err(FtuiError::TextFlagUnknownTailwindToken(style.to_string())),
+                }))
+            })?;
 
         result.ensure_compatibility()?;
         Ok(result)
@@ -122,7 +149,10 @@ impl TextFlags {
 
     #[inline]
     pub fn default_header() -> TextFlags {
-        TextFlags::ALIGN_MIDDLE | TextFlags::COLOR_GREEN_BACK | TextFlags::STYLE_BOLD
+        TextFlags::ALIGN_MIDDLE |
+        TextFlags::FILL_LINE |
+        TextFlags::COLOR_GREEN_BACK |
+        TextFlags::STYLE_BOLD
     }
 
     pub(crate) fn ensure_compatibility(&self) -> FtuiResult<()> {
@@ -156,57 +186,36 @@ impl TextFlags {
         Ok(())
     }
 
-    #[inline]
-    fn color_f_or_b(flags: TextFlags, b: &'static str, f: &'static str) -> &'static str {
-        // if COLOR_BACK flag is not set text will default to foreground color
-        if flags.contains(TextFlags::COLOR_BACK) { b } else { f }
-    }
-
-    fn resolve_color(&self) -> Option<&'static str> {
-        if self.contains(TextFlags::COLOR_BLACK) {
-            Some(Self::color_f_or_b(*self, ansi::ESC_BLACK_B, ansi::ESC_BLACK_F))
+    /// Returns the color set in the flags, as a foreground or background
+    /// `Colors` depending on `COLOR_BACK`.
+    pub(crate) fn color(&self) -> Option<Colors> {
+        let back = self.contains(TextFlags::COLOR_BACK);
+        let (fore_color, back_color) = if self.contains(TextFlags::COLOR_BLACK) {
+            (Colors::BlackFore, Colors::BlackBack)
         } else if self.contains(TextFlags::COLOR_RED) {
-            Some(Self::color_f_or_b(*self, ansi::ESC_RED_B, ansi::ESC_RED_F))
+            (Colors::RedFore, Colors::RedBack)
         } else if self.contains(TextFlags::COLOR_GREEN) {
-            Some(Self::color_f_or_b(*self, ansi::ESC_GREEN_B, ansi::ESC_GREEN_F))
+            (Colors::GreenFore, Colors::GreenBack)
         } else if self.contains(TextFlags::COLOR_YELLOW) {
-            Some(Self::color_f_or_b(*self, ansi::ESC_YELLOW_B, ansi::ESC_YELLOW_F))
+            (Colors::YellowFore, Colors::YellowBack)
         } else if self.contains(TextFlags::COLOR_BLUE) {
-            Some(Self::color_f_or_b(*self, ansi::ESC_BLUE_B, ansi::ESC_BLUE_F))
+            (Colors::BlueFore, Colors::BlueBack)
         } else if self.contains(TextFlags::COLOR_MAGENTA) {
-            Some(Self::color_f_or_b(*self, ansi::ESC_MAGENTA_B, ansi::ESC_MAGENTA_F))
+            (Colors::MagentaFore, Colors::MagentaBack)
         } else if self.contains(TextFlags::COLOR_CYAN) {
-            Some(Self::color_f_or_b(*self, ansi::ESC_CYAN_B, ansi::ESC_CYAN_F))
+            (Colors::CyanFore, Colors::CyanBack)
         } else if self.contains(TextFlags::COLOR_WHITE) {
-            Some(Self::color_f_or_b(*self, ansi::ESC_WHITE_B, ansi::ESC_WHITE_F))
+            (Colors::WhiteFore, Colors::WhiteBack)
         } else {
-            None
-        }
-    }
+            return None;
+        };
 
-    pub(crate) fn resolve_ansi(&self) -> Vec<&'static str> {
-        let mut style: Vec<&'static str> = vec![];
-
-        if let Some(color) =  self.resolve_color() {
-            style.push(color);
-        }
-        if self.contains(TextFlags::STYLE_BOLD) {
-            style.push(ansi::ESC_BOLD);
-        }
-        if self.contains(TextFlags::STYLE_DIM) {
-            style.push(ansi::ESC_DIM);
-        }
-        if self.contains(TextFlags::STYLE_ITALIC) {
-            style.push(ansi::ESC_ITALIC);
-        }
-        if self.contains(TextFlags::STYLE_UNDER) {
-            style.push(ansi::ESC_UNDERLINE);
-        }
-        if self.contains(TextFlags::STYLE_STRIKE) {
-            style.push(ansi::ESC_STRIKETHROUGH);
-        }
+        Some(if back { back_color } else { fore_color })
+    }
 
-        return style;
+    #[inline]
+    pub(crate) fn resolve_ansi(&self) -> Vec<Ansi> {
+        Style::from(*self).resolve_ansi()
     }
 }
 
@@ -218,47 +227,158 @@ impl TextFlags {
 /// # Usage
 /// The `Text` component is used within a `Container` to display static text elements.  
 ///
+/// # Notes
+/// Colors and styles only cover the label. Add `TextFlags::FILL_LINE` to extend
+/// them across the whole line.
+///
 /// # Derives
 /// `Debug`, `Clone`, `PartialEq`, `Eq`
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Text {
     label: String,
-    len: usize,
+    width: usize,
     id: GeneratedId,
     line: u16,
     flags: TextFlags,
     pos: u16,
-    style: Vec<&'static str>,
+    style: Style,
+    ansi: Vec<Ansi>,
+    flash: Option<(Vec<Ansi>, u32)>,
+    dots: Option<Dots>,
+    rainbow: Option<usize>,
+    link: Option<String>,
+    priority: Option<u32>,
+    hidden: bool,
+    runs: Vec<(usize, usize, Vec<Ansi>)>,
+    control_chars: ControlChars,
+}
+
+/// How control characters such as `\r` or `\0` in a `Text` label are handled.
+/// Written as is, they move the cursor or corrupt the terminal.
+///
+/// # Notes
+/// - Tabs are always expanded to spaces.
+/// - Zero-width spaces, word joiners and byte order marks are always removed,
+///   as they take no column but would take a cell. Zero-width joiners and
+///   non-joiners are kept, emoji sequences and many scripts need them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ControlChars {
+    /// Removes control characters from the label.
+    #[default]
+    Strip,
+    /// Replaces each control character with `�`.
+    Replace,
+    /// Fails with `FtuiError::TextControlChar`.
+    Error,
+}
+
+/// Returns whether `c` takes no column on the terminal and is removed from labels.
+fn is_zero_width(c: char) -> bool {
+    matches!(c, '\u{200B}' | '\u{2060}' | '\u{FEFF}')
+}
+
+/// Returns the first control character in `label` other than a tab, and
+/// other than an escape if `keep_escape` is set.
+fn find_control(label: &str, keep_escape: bool) -> Option<char> {
+    label.chars().find(|&c| {
+        c.is_control() && c != '\t' && !(keep_escape && c == '\x1b')
+    })
+}
+
+/// Expands tabs, removes zero-width characters and removes or replaces control
+/// characters in `label`. Escapes are kept if `keep_escape` is set.
+fn sanitize(label: &str, replace: bool, keep_escape: bool) -> String {
+    let mut sanitized = String::with_capacity(label.len());
+
+    for c in label.chars() {
+        match c {
+            '\t' => sanitized.extend(std::iter::repeat_n(' ', TAB_WIDTH)),
+            '\x1b' if keep_escape => sanitized.push(c),
+            c if c.is_control() => if replace { sanitized.push(REPLACEMENT_CHAR) },
+            c if is_zero_width(c) => {},
+            c => sanitized.push(c),
+        }
+    }
+
+    sanitized
+}
+
+/// The state of a `Text` animated with trailing dots.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Dots {
+    base: String,
+    max: usize,
+    count: usize,
 }
 
 impl Text {
     /// Creates a new `Text` component with the specified label and flags.
     ///
     /// # Notes
-    /// The bitwise OR operator combines flags like this: `flag1 | flag2 | flag3`
+    /// - The bitwise OR operator combines flags like this: `flag1 | flag2 | flag3`
+    /// - Control characters are removed from the label.
     ///
     /// # Returns
     /// - `Ok(Text)`: Returns a `Text` instance
     /// - `Err(FtuiError)`: Returns an error.
     pub(crate) fn new(
         label: impl ToString, flags: impl Into<Option<TextFlags>>
+    ) -> FtuiResult<Self> {
+        Text::new_with_control(label, flags, ControlChars::default())
+    }
+
+    /// Creates a new `Text`, handling control characters in the label and in
+    /// later labels as `control` says.
+    pub(crate) fn new_with_control(
+        label: impl ToString, flags: impl Into<Option<TextFlags>>, control: ControlChars
     ) -> FtuiResult<Self> {
         let flags = flags.into().unwrap_or(TextFlags::NONE);
         let label = label.to_string();
 
         flags.ensure_compatibility()?;
+
+        if control == ControlChars::Error && let Some(c) = find_control(&label, false) {
+            return Err(FtuiError::TextControlChar(c));
+        }
+
+        let label = sanitize(&label, control == ControlChars::Replace, false);
         
         Ok(Text {
-            len: label.graphemes(true).count(),
+            width: util::display_width(&label),
             label: label,
             id: 0,
             line: 0,
             flags,
             pos: 0,
-            style: flags.resolve_ansi(),
+            style: Style::from(flags),
+            ansi: flags.resolve_ansi(),
+            flash: None,
+            dots: None,
+            rainbow: None,
+            link: None,
+            priority: None,
+            hidden: false,
+            runs: vec![],
+            control_chars: control,
         })
     }
 
+    /// Creates a new `Text` from a label already styled with ANSI escapes,
+    /// such as colored output from another tool. Only the visible text is
+    /// measured, and the colors and styles of the escapes are kept on top of
+    /// the `Text`'s own.
+    pub(crate) fn new_prestyled(
+        label: &str, flags: impl Into<Option<TextFlags>>, control: ControlChars
+    ) -> FtuiResult<Self> {
+        if control == ControlChars::Error && let Some(c) = find_control(label, true) {
+            return Err(FtuiError::TextControlChar(c));
+        }
+
+        let mut text = Text::new_with_control("", flags, control)?;
+        text.set_prestyled_label(label);
+        Ok(text)
+    }
+
     pub(crate) fn with_id(
         label: impl ToString, flags: impl Into<Option<TextFlags>>, id: GeneratedId
     ) -> FtuiResult<Self> {
@@ -267,19 +387,19 @@ impl Text {
         Ok(text)
     }
 
-    pub(crate) fn resolve_pos_custom_len(&mut self, renderer_width: u16, len: usize) {
+    fn calc_pos(&self, renderer_width: u16, len: usize) -> u16 {
         if self.flags.contains(TextFlags::ALIGN_MIDDLE) {
-            self.pos = ((renderer_width as f32 - len as f32) / 2.0).round() as u16 
+            ((renderer_width as f32 - len as f32) / 2.0).round() as u16 
         } else if self.flags.contains(TextFlags::ALIGN_RIGHT) {
-            self.pos = (renderer_width as usize - len) as u16
+            (renderer_width as usize - len) as u16
         } else {
-            self.pos = 0
+            0
         }
     }
 
     #[inline]
-    pub(crate) fn resolve_pos(&mut self, renderer_width: u16) {
-        self.resolve_pos_custom_len(renderer_width, self.len);
+    pub(crate) fn resolve_pos_custom_len(&mut self, renderer_width: u16, len: usize) {
+        self.pos = self.calc_pos(renderer_width, len);
     }
 
     pub fn label(&self) -> &String {
@@ -288,6 +408,11 @@ impl Text {
 
     /// Updates the label of the `Text` component.
     ///
+    /// # Notes
+    /// - Control characters are handled as set when the `Text` was created,
+    ///   except that this cannot fail, so with `ControlChars::Error` they are
+    ///   replaced with `�` instead.
+    ///
     /// # Parameters
     /// - `label`: The new label.
     ///
@@ -300,74 +425,429 @@ impl Text {
     /// text.set_label("New Label");
     /// ```
     pub fn set_label(&mut self, label: impl ToString) {
-        let label = label.to_string();
+        self.dots = None;
+        self.update_label(label.to_string());
+    }
+
+    /// Updates the label with one already styled with ANSI escapes, such as
+    /// colored output from another tool. The escapes are removed from the
+    /// label and only its visible text is measured, so alignment and width
+    /// checks stay correct. Their colors and styles are kept when rendered,
+    /// on top of the `Text`'s own. Other escape sequences are dropped.
+    ///
+    /// # Parameters
+    /// - `label`: The new label, with ANSI escapes.
+    ///
+    /// # Example
+    /// ```rust
+    /// let output = Command::new("git").args(["status", "-s", "--color=always"]).output()?;
+    /// text.set_prestyled_label(&String::from_utf8_lossy(&output.stdout));
+    /// ```
+    pub fn set_prestyled_label(&mut self, label: &str) {
+        // Sanitized before parsing, as the runs index the visible text.
+        let label = sanitize(label, self.control_chars != ControlChars::Strip, true);
+        let (label, runs) = ansi::parse_styled(&label);
+
+        self.set_label(label);
+        self.runs = runs;
+    }
 
-        self.len = label.graphemes(true).count();
+    fn update_label(&mut self, label: String) {
+        let label = sanitize(&label, self.control_chars != ControlChars::Strip, false);
+
+        self.width = util::display_width(&label);
         self.label = label;
+        self.runs.clear();
     }
 
-    pub(crate) fn set_line(&mut self, line: u16) {
-        self.line = line;
+    /// Turns the `Text` into a loading indicator: `base` followed by trailing
+    /// dots, one more on every `tick` and back to none after `max_dots`.
+    ///
+    /// `Loading`, `Loading.`, `Loading..`, `Loading...`, `Loading`, ...
+    ///
+    /// # Notes
+    /// - The label is padded to the full `base` plus `max_dots` width, so
+    ///   aligned text does not move as the dots change, and it must fit the
+    ///   `Renderer` like any other label.
+    /// - Calling `set_label` stops the animation.
+    ///
+    /// # Parameters
+    /// - `base`: The label before the dots.
+    /// - `max_dots`: The maximum number of dots.
+    ///
+    /// # Example
+    /// ```rust
+    /// status.animated_dots("Loading", 3);
+    ///
+    /// loop {
+    ///     status.tick();
+    ///     renderer.draw(&mut container)?;
+    ///     ...
+    /// }
+    /// ```
+    pub fn animated_dots(&mut self, base: impl ToString, max_dots: usize) {
+        self.dots = Some(Dots { base: base.to_string(), max: max_dots, count: 0 });
+        self.update_dots();
     }
 
-    pub(crate) fn line(&self) -> u16 {
-        return self.line;
+    /// Colors every character of the label with the next color of a rainbow
+    /// palette: red, yellow, green, cyan, blue and magenta. Each `tick` shifts
+    /// the colors by one character, so drawing in a loop animates it. The
+    /// background and attributes of the `Text` are kept.
+    ///
+    /// # Notes
+    /// - A `flash_style` shows over the rainbow while it lasts.
+    ///
+    /// # Example
+    /// ```rust
+    /// title.rainbow();
+    ///
+    /// loop {
+    ///     title.tick();
+    ///     renderer.draw(&mut container)?;
+    ///     ...
+    /// }
+    /// ```
+    pub fn rainbow(&mut self) {
+        self.rainbow = Some(0);
     }
 
-    pub(crate) fn len(&self) -> usize {
-        return self.len;
+    /// Stops the rainbow started by `rainbow`, going back to the style of the
+    /// `Text`.
+    pub fn clear_rainbow(&mut self) {
+        self.rainbow = None;
+    }
+
+    /// Advances the animations of the `Text`: one more dot for
+    /// `animated_dots` and one character of shift for `rainbow`. Does nothing
+    /// if the `Text` is not animated.
+    pub fn tick(&mut self) {
+        if let Some(dots) = &mut self.dots {
+            dots.count = if dots.count >= dots.max { 0 } else { dots.count + 1 };
+            self.update_dots();
+        }
+
+        if let Some(phase) = &mut self.rainbow {
+            *phase = (*phase + 1) % RAINBOW.len();
+        }
+    }
+
+    fn update_dots(&mut self) {
+        if let Some(Dots { base, max, count }) = &self.dots {
+            let label = format!(
+                "{}{}{}", base, ".".repeat(*count), " ".repeat(max - count));
+
+            self.update_label(label);
+        }
+    }
+
+    pub(crate) fn set_line(&mut self, line: u16) {
+        self.line = line;
+    }
+
+    /// Returns the number of terminal columns the label takes when rendered,
+    /// not counting any styling. Wide characters such as CJK take two columns.
+    ///
+    /// # Returns
+    /// `usize`: The width of the label in columns.
+    ///
+    /// # Example
+    /// ```rust
+    /// assert_eq!(Text::new("Hello", None)?.display_width(), 5);
+    /// assert_eq!(Text::new("Café", None)?.display_width(), 4);
+    /// assert_eq!(Text::new("日本語", None)?.display_width(), 6);
+    /// ```
+    #[inline]
+    pub fn display_width(&self) -> usize {
+        self.width
     }
 
     pub(crate) fn pos(&self) -> u16 {
         return self.pos;
     }
 
-    pub(crate) fn styles(&self) -> &[&'static str] {
-        return &self.style;
+    /// Returns the style the `Text` is currently rendered with, the flash
+    /// style while one is active.
+    pub(crate) fn styles(&self) -> &[Ansi] {
+        match &self.flash {
+            Some((style, _)) => style,
+            None => &self.ansi,
+        }
+    }
+
+    /// Replaces the colors and attributes of the `Text` with a `Style`. The
+    /// alignment set by its flags is kept.
+    ///
+    /// # Parameters
+    /// - `style`: A `Style`, or `TextFlags` to convert into one.
+    ///
+    /// # Example
+    /// ```rust
+    /// text.set_style(Style::new().fg(Colors::YellowFore).bg(Colors::BlueBack).bold());
+    /// ```
+    pub fn set_style(&mut self, style: impl Into<Style>) {
+        self.style = style.into();
+        self.ansi = self.style.resolve_ansi();
+    }
+
+    /// Sets the foreground color of the `Text`, keeping the rest of its style.
+    ///
+    /// # Parameters
+    /// - `color`: A `Color`, or `Colors` to convert into one.
+    ///
+    /// # Example
+    /// ```rust
+    /// text.set_fg(Color::Rgb(120, 200, 40));
+    /// ```
+    pub fn set_fg(&mut self, color: impl Into<Color>) {
+        self.set_style(self.style.fg(color));
+    }
+
+    /// Sets the background color of the `Text`, keeping the rest of its style.
+    ///
+    /// # Parameters
+    /// - `color`: A `Color`, or `Colors` to convert into one.
+    ///
+    /// # Example
+    /// ```rust
+    /// text.set_bg(Color::Indexed(236));
+    /// ```
+    pub fn set_bg(&mut self, color: impl Into<Color>) {
+        self.set_style(self.style.bg(color));
+    }
+
+    /// Makes the label a clickable hyperlink to `url` in terminals that
+    /// support OSC 8 links. Other terminals show the label as usual.
+    ///
+    /// # Parameters
+    /// - `url`: The link target.
+    ///
+    /// # Example
+    /// ```rust
+    /// docs.set_link("https://docs.rs/feather-tui");
+    /// ```
+    pub fn set_link(&mut self, url: impl ToString) {
+        self.link = Some(url.to_string());
+    }
+
+    /// Removes the link set with `set_link`.
+    pub fn clear_link(&mut self) {
+        self.link = None;
+    }
+
+    /// Temporarily renders the `Text` with a different style for the next
+    /// `frames` renders, then reverts to its own style. Useful for briefly
+    /// highlighting a value that changed. Calling it again restarts the flash.
+    ///
+    /// # Notes
+    /// - Alignment flags are ignored, the `Text` keeps its position.
+    /// - The countdown advances once per render of this `Text`.
+    ///
+    /// # Parameters
+    /// - `flags`: The `TextFlags` to flash with.
+    /// - `frames`: For how many renders the flash lasts.
+    ///
+    /// # Returns
+    /// - `Ok(())`: Returns nothing.
+    /// - `Err(FtuiError)`: Returns an error.
+    ///
+    /// # Example
+    /// ```rust
+    /// // The price went up, flash it green for the next 5 frames.
+    /// price.set_label(new_price);
+    /// price.flash_style(TextFlags::COLOR_GREEN, 5)?;
+    /// ```
+    pub fn flash_style(&mut self, flags: TextFlags, frames: u32) -> FtuiResult<()> {
+        flags.ensure_compatibility()?;
+        self.flash = (frames != 0).then(|| (flags.resolve_ansi(), frames));
+        Ok(())
+    }
+
+    /// Advances the flash countdown by one render.
+    pub(crate) fn tick_flash(&mut self) {
+        if let Some((_, frames)) = &mut self.flash {
+            *frames -= 1;
+
+            if *frames == 0 {
+                self.flash = None;
+            }
+        }
     }
 
     pub(crate) fn id(&self) -> GeneratedId {
         self.id
     }
 
+    /// Returns the priority of an optional `Text`, or `None` if it is always
+    /// shown.
+    #[inline]
+    pub(crate) fn priority(&self) -> Option<u32> {
+        self.priority
+    }
+
+    #[inline]
+    pub(crate) fn set_priority(&mut self, priority: u32) {
+        self.priority = Some(priority);
+    }
+
+    #[inline]
+    pub(crate) fn is_hidden(&self) -> bool {
+        self.hidden
+    }
+
+    #[inline]
+    pub(crate) fn set_hidden(&mut self, hidden: bool) {
+        self.hidden = hidden;
+    }
+
     pub(crate) fn set_id(&mut self, value: GeneratedId) {
         self.id = value;
     }
 }
 
-impl RenderableMut<Renderer> for Text {
-    fn render(&mut self, renderer: &mut Renderer) -> FtuiResult<()> {
+impl Text {
+    /// Returns how many rows the `Text` takes in a `Renderer` `width` columns
+    /// wide. A label that does not fit without wrapping counts as one row,
+    /// the error is left to `render_with`.
+    pub(crate) fn row_count(&self, width: u16, overflow: OverflowPolicy) -> u16 {
+        overflow
+            .rows(&self.label, self.width, width as usize)
+            .map_or(1, |rows| rows.len() as u16)
+    }
+
+    /// Renders the `Text` from its line down, fitting the label to the
+    /// `Renderer` width according to `overflow`. Only `OverflowPolicy::Wrap`
+    /// takes more than one line.
+    pub(crate) fn render_with(
+        &mut self, renderer: &mut Renderer, overflow: OverflowPolicy
+    ) -> FtuiResult<()> {
         let (width, _) = renderer.get_dimensions();
-        renderer.ensure_label_inbound(self.len())?;
+        let rows = overflow.rows(&self.label, self.width, width as usize)?;
+        let mut cursor = 0;
+
+        for (i, row) in rows.iter().enumerate() {
+            // Wrapped rows are pieces of the label, found in order to keep the
+            // runs and the rainbow in step with it. A truncated row starts it.
+            let at = self.label[cursor..].find(row.as_ref()).map_or(cursor, |at| cursor + at);
+            let before = &self.label[..at];
+            cursor = at + row.len().min(self.label.len() - at);
 
-        let line = renderer.line_mut(self.line as usize);
+            let pos = self.calc_pos(width, util::display_width(row).min(width as usize));
+            let line = renderer.line_mut(self.line as usize + i);
+
+            self.render_row(
+                line, row, pos, before.chars().count(), before.graphemes(true).count());
+
+            if i == 0 {
+                self.pos = pos;
+            }
+        }
 
-        self.resolve_pos(width);
-        line.edit(self.label(), self.pos());
-        line.add_ansi_many(self.styles());
+        self.tick_flash();
 
         Ok(())
     }
+
+    /// Renders `row`, the part of the label starting at character `start` and
+    /// grapheme `grapheme_start`, onto `line` at column `pos`.
+    fn render_row(
+        &self, line: &mut Line, row: &str, pos: u16, start: usize, grapheme_start: usize
+    ) {
+        let row_len = row.chars().count();
+
+        line.edit(row, pos);
+
+        // Only the label is styled unless the whole line should be.
+        if self.flags.contains(TextFlags::FILL_LINE) {
+            line.add_ansi_many(self.styles());
+        }
+
+        match self.rainbow {
+            Some(phase) if self.flash.is_none() => {
+                let mut begin = pos as usize;
+
+                // One span per grapheme, as spans do not nest.
+                for (i, grapheme) in row.graphemes(true).enumerate() {
+                    let i = grapheme_start + i;
+                    let len = grapheme.chars().count();
+                    let color = RAINBOW[(i + RAINBOW.len() - phase) % RAINBOW.len()];
+
+                    line.add_span(begin, len, self.style.fg(color).resolve_ansi());
+                    begin += len;
+                }
+            }
+            // Spans do not nest, so every run carries the style of the `Text`
+            // too.
+            _ if !self.runs.is_empty() => {
+                for (begin, len, style) in &self.runs {
+                    // The part of the run on this row.
+                    let run_begin = (*begin).max(start);
+                    let run_end = (begin + len).min(start + row_len);
+
+                    if run_begin >= run_end {
+                        continue;
+                    }
+
+                    let mut ansi = self.styles().to_vec();
+                    ansi.extend(style.iter().cloned());
+
+                    line.add_span(pos as usize + run_begin - start, run_end - run_begin, ansi);
+                }
+            }
+            _ if !self.flags.contains(TextFlags::FILL_LINE) => {
+                line.add_span(pos as usize, row_len, self.styles().to_vec());
+            }
+            _ => {},
+        }
+
+        if let Some(url) = &self.link {
+            line.add_link(pos as usize, row_len, url.clone());
+        }
+    }
+}
+
+impl RenderableMut<Renderer> for Text {
+    fn render(&mut self, renderer: &mut Renderer) -> FtuiResult<()> {
+        self.render_with(renderer, OverflowPolicy::Error)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TextsManager {
     components: Vec<Text>,
+    index: IdIndex,
 }
 
 impl TextsManager {
     pub(crate) fn new() -> Self {
         Self {
-            components: Vec::new()
+            components: Vec::new(),
+            index: IdIndex::default(),
         }
     }
 
     #[inline]
     pub(crate) fn add(&mut self, component: Text) {
+        self.index.insert(component.id(), self.components.len());
         self.components.push(component);
     }
 
-    /// Queryan `Text` component by its ID (`O(n)` lookup).
+    /// Enables `O(1)` lookups by ID.
+    pub(crate) fn enable_index(&mut self) {
+        self.index.enable(self.components.iter().map(|text| text.id()));
+    }
+
+    #[inline]
+    pub(crate) fn clear(&mut self) {
+        self.components.clear();
+        self.index.rebuild(std::iter::empty());
+    }
+
+    fn find(&self, id: GeneratedId) -> Option<usize> {
+        self.index.find(id, || self.components.iter().position(|text| text.id() == id))
+    }
+
+    /// Queryan `Text` component by its ID (`O(n)` lookup, `O(1)` when indexed).
     ///
     /// # Parameters
     /// - `id`: The ID of the `Text` component to query.
@@ -390,10 +870,10 @@ impl TextsManager {
     /// ```
     #[inline]
     pub fn query(&self, id: GeneratedId) -> Option<&Text> {
-        self.components.iter().find(|text| text.id() == id)
+        self.find(id).map(|index| &self.components[index])
     }
 
-    /// Query an `Text` component by its ID (`O(n)` lookup).
+    /// Query an `Text` component by its ID (`O(n)` lookup, `O(1)` when indexed).
     ///
     /// # Parameters
     /// - `id`: The ID of the `Text` component to query.
@@ -416,7 +896,12 @@ impl TextsManager {
     /// ```
     #[inline]
     pub fn query_mut(&mut self, id: GeneratedId) -> Option<&mut Text> {
-        self.components.iter_mut().find(|text| text.id() == id)
+        self.find(id).map(|index| &mut self.components[index])
+    }
+
+    #[inline]
+    pub(crate) fn comps(&self) -> &[Text] {
+        &self.components
     }
 
     pub(crate) fn comps_mut(&mut self) -> &mut [Text] {
@@ -424,12 +909,47 @@ impl TextsManager {
     }
 }
 
-impl RenderableMut<Renderer> for TextsManager {
-    fn render(&mut self, renderer: &mut Renderer) -> FtuiResult<()> {
-        for text in self.comps_mut().iter_mut() {
-            text.render(renderer)?;
+impl TextsManager {
+    /// Returns how many rows every `Text` takes in a `Renderer` `width`
+    /// columns wide, hidden or not.
+    pub(crate) fn row_counts(&self, width: u16, overflow: OverflowPolicy) -> Vec<u16> {
+        self.comps().iter().map(|text| text.row_count(width, overflow)).collect()
+    }
+
+    pub(crate) fn render_with(
+        &mut self, renderer: &mut Renderer, overflow: OverflowPolicy
+    ) -> FtuiResult<()> {
+        for text in self.comps_mut().iter_mut().filter(|text| !text.is_hidden()) {
+            text.render_with(renderer, overflow)?;
         }
 
         Ok(())
     }
 }
+
+impl RenderableMut<Renderer> for TextsManager {
+    fn render(&mut self, renderer: &mut Renderer) -> FtuiResult<()> {
+        self.render_with(renderer, OverflowPolicy::Error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tailwind_parses_known_tokens() {
+        assert_eq!(
+            TextFlags::tailwind("c-r s-b a-m"),
+            Ok(TextFlags::COLOR_RED | TextFlags::STYLE_BOLD | TextFlags::ALIGN_MIDDLE),
+        );
+    }
+
+    #[test]
+    fn tailwind_rejects_unknown_tokens() {
+        assert_eq!(
+            TextFlags::tailwind("c-r bold"),
+            Err(FtuiError::TextFlagUnknownTailwindToken("bold".to_string())),
+        );
+    }
+}
@@ -1,10 +1,24 @@
+use std::cell::Cell;
 use std::path::Path;
 use std::fs;
 
+use crossterm as ct;
+
+use unicode_segmentation::UnicodeSegmentation;
+
 use crate::components::Text;
+use crate::containers::Scrollable;
+use crate::containers::ScrollKeys;
+use crate::containers::scrollable::Scrollbar;
+use crate::containers::wrap::Wrapper;
+use crate::containers::WrapStrategy;
 use crate::components::TextFlags;
+use crate::error::FtuiError;
 use crate::error::FtuiResult;
 use crate::renderer::Renderer;
+use crate::util::ansi::Ansi;
+use crate::util::Colors;
+use crate::util::OverflowPolicy;
 use crate::util::RenderableMut;
 
 /// A specialized variant of container for displaying long-form text.  
@@ -14,16 +28,64 @@ use crate::util::RenderableMut;
 /// # Usage
 /// Use `Document` when you need to present lengthy text with proper  
 /// wrapping and navigation.
+///
+/// # Selection
+/// A `Document` has a cursor row that can be moved with `cursor_up` and
+/// `cursor_down`. Calling `start_selection` anchors a selection at the cursor,
+/// and every row between the anchor and the cursor is highlighted.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Document {
     header: Option<Text>,
     footer: Option<Text>,
     data: String,
     offset: usize,
+    scroll_step: usize,
     flags: TextFlags,
-    style: Vec<&'static str>,
+    style: Vec<Ansi>,
+    cursor: usize,
+    anchor: Option<usize>,
+    follow_cursor: bool,
+    highlight: Colors,
+    last_width: Option<usize>,
+    viewport: usize,
+    wrapper: Wrapper,
+    scroll_keys: ScrollKeys,
+    scrollbar: Option<Scrollbar>,
+    row_count: RowCount,
 }
 
+/// The number of rows the content wraps onto at a width, cached as counting
+/// them wraps the whole content. Ignored when comparing `Document`s.
+#[derive(Debug, Clone, Default)]
+struct RowCount(Cell<Option<(usize, usize)>>);
+
+impl RowCount {
+    /// Returns the cached count if it was counted at `width`.
+    #[inline]
+    fn get(&self, width: usize) -> Option<usize> {
+        self.0.get().filter(|(at, _)| *at == width).map(|(_, rows)| rows)
+    }
+
+    #[inline]
+    fn set(&self, width: usize, rows: usize) {
+        self.0.set(Some((width, rows)));
+    }
+
+    /// Forgets the count, for when the content or the wrapping changed.
+    #[inline]
+    fn clear(&self) {
+        self.0.set(None);
+    }
+}
+
+impl PartialEq for RowCount {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for RowCount {}
+
 impl Document {
     pub(crate) fn new() -> Self {
         Self {
@@ -31,12 +93,51 @@ impl Document {
             footer: None,
             data: String::new(),
             offset: 0,
+            scroll_step: 1,
             flags: TextFlags::NONE,
             style: Vec::new(), 
+            cursor: 0,
+            anchor: None,
+            follow_cursor: false,
+            highlight: Colors::CyanBack,
+            last_width: None,
+            viewport: 0,
+            wrapper: Wrapper::default(),
+            scroll_keys: ScrollKeys::default(),
+            scrollbar: None,
+            row_count: RowCount::default(),
         }
     }
 
-    /// Attempts to scroll the `Document` up by one position.
+    /// Scrolls the `Document` according to a key press, making it a ready to
+    /// use pager. The keys follow the `ScrollKeys` set with
+    /// `DocumentBuilder::scroll_keys`, vim and arrow bindings by default.
+    ///
+    /// # Parameters
+    /// - `key`: A `KeyCode`, or a `KeyEvent` for bindings using `Ctrl`.
+    ///
+    /// # Returns
+    /// - `true`: The `Document` scrolled.
+    /// - `false`: The key is not bound, or the `Document` did not move.
+    ///
+    /// # Example
+    /// ```rust
+    /// loop {
+    ///     renderer.draw(&mut document)?;
+    ///
+    ///     if let Event::Key(key) = event::read()? {
+    ///         if key.code == KeyCode::Char('q') {
+    ///             break;
+    ///         }
+    ///         document.handle_key(key);
+    ///     }
+    /// }
+    /// ```
+    pub fn handle_key(&mut self, key: impl Into<ct::event::KeyEvent>) -> bool {
+        self.scroll_keys.apply(self, key.into())
+    }
+
+    /// Attempts to scroll the `Document` up by the scroll step (one by default).
     ///
     /// # Returns
     /// - `true` if the `Document` was successfully scrolled up.
@@ -50,16 +151,12 @@ impl Document {
     /// // Initially at the top, so scrolling up does nothing.
     /// assert_eq!(doc.scroll_up(), false);
     /// ```
+    #[inline]
     pub fn scroll_up(&mut self) -> bool {
-        if self.offset != 0 {
-            self.offset -= 1;
-            true
-        } else {
-            false
-        }
+        Scrollable::scroll_up(self)
     }
 
-    /// Attempts to scroll the `Document` down by one position.
+    /// Attempts to scroll the `Document` down by the scroll step (one by default).
     ///
     /// # Returns
     /// - `true` If the `Document` was successfully scrolled down.
@@ -77,15 +174,271 @@ impl Document {
     /// ```
     #[inline]
     pub fn scroll_down(&mut self) -> bool {
-        // Bounds checking is done in the `Renderer`.
-        self.offset += 1;
-        true
+        Scrollable::scroll_down(self)
+    }
+
+    /// Returns the content of the `Document`.
+    #[inline]
+    pub fn content(&self) -> &str {
+        &self.data
+    }
+
+    /// Replaces the content of the `Document`, for example to refresh a live
+    /// log. The scroll offset, cursor and selection are kept where they are,
+    /// and pulled back to the last row if the new content is shorter.
+    ///
+    /// # Parameters
+    /// - `data`: Any type that implements `ToString`, representing the new content.
+    ///
+    /// # Example
+    /// ```rust
+    /// doc.set_content(fs::read_to_string("app.log")?);
+    /// renderer.draw(&mut doc)?;
+    /// ```
+    pub fn set_content(&mut self, data: impl ToString) {
+        self.data = data.to_string();
+        self.row_count.clear();
+        self.clamp_to(Scrollable::content_len(self));
+    }
+
+    /// Sets how many rows `scroll_up` and `scroll_down` move by. Scrolling
+    /// still stops at the first and last row.
+    ///
+    /// # Parameters
+    /// - `step`: The number of rows to scroll by. Defaults to 1, and a step of
+    ///   0 is treated as 1.
+    ///
+    /// # Example
+    /// ```rust
+    /// // Scroll three rows at a time, like a mouse wheel notch.
+    /// doc.set_scroll_step(3);
+    /// ```
+    #[inline]
+    pub fn set_scroll_step(&mut self, step: usize) {
+        self.scroll_step = step.max(1);
+    }
+
+    /// Scrolls the `Document` down to the start of the next paragraph.
+    /// Paragraphs are blocks of lines separated by blank lines; a `Document`
+    /// without blank lines is a single paragraph.
+    ///
+    /// # Notes
+    /// Rows are counted at the width of the last render. Before the first
+    /// render, every line is assumed to fit on one row.
+    ///
+    /// # Returns
+    /// - `true`: The `Document` scrolled to the next paragraph.
+    /// - `false`: There is no paragraph below the current offset, or the
+    ///   `Document` is already scrolled as far down as it goes.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut doc = DocumentBuilder::new()
+    ///     .content("First\n\nSecond")
+    ///     .build();
+    ///
+    /// assert_eq!(doc.scroll_paragraph_down(), true);
+    /// assert_eq!(doc.scroll_paragraph_down(), false);
+    /// ```
+    pub fn scroll_paragraph_down(&mut self) -> bool {
+        let next = self
+            .paragraph_starts()
+            .into_iter()
+            .find(|&start| start > self.offset);
+
+        match next {
+            Some(start) => self.scroll_to(start),
+            None => false,
+        }
+    }
+
+    /// Scrolls the `Document` up to the start of the previous paragraph, or to
+    /// the top if there is none. See `scroll_paragraph_down`.
+    ///
+    /// # Returns
+    /// - `true`: The `Document` scrolled up.
+    /// - `false`: The `Document` is already at the top.
+    pub fn scroll_paragraph_up(&mut self) -> bool {
+        let prev = self
+            .paragraph_starts()
+            .into_iter()
+            .rfind(|&start| start < self.offset)
+            .unwrap_or(0);
+
+        self.scroll_to(prev)
+    }
+
+    /// Returns the first row of every paragraph.
+    fn paragraph_starts(&self) -> Vec<usize> {
+        let mut starts = vec![];
+        let mut row = 0;
+        let mut after_blank = true;
+
+        for line in self.lines() {
+            let is_blank = line.trim().is_empty();
+
+            if after_blank && !is_blank {
+                starts.push(row);
+            }
+
+            after_blank = is_blank;
+            row += self.wrapper.wrap(line, self.last_width.unwrap_or(usize::MAX)).len();
+        }
+
+        starts
+    }
+
+    /// Moves the cursor up by one row.
+    ///
+    /// # Returns
+    /// - `true`: The cursor moved up.
+    /// - `false`: The cursor is already on the first row.
+    pub fn cursor_up(&mut self) -> bool {
+        if self.cursor != 0 {
+            self.cursor -= 1;
+            self.follow_cursor = true;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Moves the cursor down by one row. The view scrolls to keep the cursor
+    /// visible on the next render.
+    ///
+    /// # Returns
+    /// - `true`: The cursor moved down.
+    /// - `false`: The cursor is already on the last row.
+    pub fn cursor_down(&mut self) -> bool {
+        if self.cursor + 1 < Scrollable::content_len(self) {
+            self.cursor += 1;
+            self.follow_cursor = true;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns the row the cursor is on.
+    #[inline]
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Starts a selection anchored at the cursor row. Moving the cursor then
+    /// extends the selection.
+    ///
+    /// # Example
+    /// ```rust
+    /// // Select rows 2 to 4.
+    /// doc.cursor_down();
+    /// doc.cursor_down();
+    /// doc.start_selection();
+    /// doc.cursor_down();
+    /// doc.cursor_down();
+    ///
+    /// assert_eq!(doc.selection_range(), Some((2, 4)));
+    /// ```
+    #[inline]
+    pub fn start_selection(&mut self) {
+        self.anchor = Some(self.cursor);
+    }
+
+    /// Ends the current selection, if any.
+    #[inline]
+    pub fn clear_selection(&mut self) {
+        self.anchor = None;
+    }
+
+    /// Returns the selected rows as an inclusive `(first, last)` range.
+    ///
+    /// # Returns
+    /// - `Some((usize, usize))`: The first and last selected rows.
+    /// - `None`: No selection has been started.
+    pub fn selection_range(&self) -> Option<(usize, usize)> {
+        self.anchor
+            .map(|anchor| (anchor.min(self.cursor), anchor.max(self.cursor)))
+    }
+
+    #[inline]
+    fn is_selected(&self, row: usize) -> bool {
+        self.selection_range()
+            .is_some_and(|(first, last)| (first..=last).contains(&row))
     }
 
     #[inline]
     pub(crate) fn offset_ensure_in_bound(&mut self, bound: usize) {
         self.offset = self.offset.min(bound);
     }
+
+    /// Clamps the offset, cursor and selection to `rows` rows of content.
+    fn clamp_to(&mut self, rows: usize) {
+        let last = rows.saturating_sub(1);
+
+        self.offset_ensure_in_bound(last);
+        self.cursor = self.cursor.min(last);
+        self.anchor = self.anchor.map(|anchor| anchor.min(last));
+    }
+
+    /// Clamps the offset, cursor and selection to `rows` rows of content, and
+    /// scrolls to the cursor if it moved since the last render.
+    fn ensure_in_bound(&mut self, rows: usize, visible: usize) {
+        self.clamp_to(rows);
+
+        if std::mem::take(&mut self.follow_cursor) {
+            if self.cursor < self.offset {
+                self.offset = self.cursor;
+            } else if self.cursor >= self.offset + visible {
+                self.offset = self.cursor + 1 - visible;
+            }
+        }
+    }
+
+    /// Returns the lines of the content, without the line endings.
+    fn lines(&self) -> impl Iterator<Item = &str> {
+        self.data.split('\n').map(|line| line.trim_end_matches('\r'))
+    }
+
+    /// Splits the content into the rows it wraps onto with the `WrapStrategy`.
+    fn rows(&self, width: usize) -> Vec<String> {
+        self.lines()
+            .flat_map(|line| self.wrapper.wrap(line, width))
+            .collect()
+    }
+}
+
+// Rows are counted at the width of the last render. Before the first render,
+// every line is assumed to fit on one row.
+impl Scrollable for Document {
+    #[inline]
+    fn offset(&self) -> usize {
+        self.offset
+    }
+
+    #[inline]
+    fn set_offset(&mut self, offset: usize) {
+        self.offset = offset;
+    }
+
+    fn content_len(&self) -> usize {
+        let width = self.last_width.unwrap_or(usize::MAX);
+
+        self.row_count.get(width).unwrap_or_else(|| {
+            let rows = self.rows(width).len();
+            self.row_count.set(width, rows);
+            rows
+        })
+    }
+
+    #[inline]
+    fn viewport(&self) -> usize {
+        self.viewport
+    }
+
+    #[inline]
+    fn scroll_step(&self) -> usize {
+        self.scroll_step
+    }
 }
 
 /// `DocumentBuilder` is used to create `Document` instances using the builder pattern.
@@ -222,7 +575,8 @@ impl DocumentBuilder {
     ///
     /// # Returns
     /// - `Ok(DocumentBuilder)`: Returns self.  
-    /// - `Err(FtuiError)`: Returns an `io` error.  
+    /// - `Err(FtuiError)`: Returns an `io` error, or
+    ///   `FtuiError::DocumentInvalidUtf8` if the file is not valid UTF-8.
     ///
     /// # Example
     /// ```
@@ -230,10 +584,160 @@ impl DocumentBuilder {
     ///     .from_file("/path/to/file.txt")?;
     /// ```
     pub fn from_file(mut self, path: impl AsRef<Path>) -> FtuiResult<Self> {
-        self.document.data = fs::read_to_string(path.as_ref())?.trim().to_owned(); 
+        let path = path.as_ref();
+        let data = String::from_utf8(fs::read(path)?)
+            .map_err(|_| FtuiError::DocumentInvalidUtf8 { path: path.to_path_buf() })?;
+
+        self.document.data = data.trim().to_owned(); 
         Ok(self)
     }
 
+    /// Loads the contents of a file and sets it as the document content,
+    /// replacing any invalid UTF-8 with `�`. This is the better choice for
+    /// viewing arbitrary files.
+    ///
+    /// # Parameters
+    /// - `path`: A path to the file to be read.
+    ///
+    /// # Returns
+    /// - `Ok(DocumentBuilder)`: Returns self.  
+    /// - `Err(FtuiError)`: Returns an `io` error.  
+    ///
+    /// # Example
+    /// ```
+    /// let builder = DocumentBuilder::new()
+    ///     .from_file_lossy("/path/to/file.bin")?;
+    /// ```
+    pub fn from_file_lossy(mut self, path: impl AsRef<Path>) -> FtuiResult<Self> {
+        let data = fs::read(path.as_ref())?;
+
+        self.document.data = String::from_utf8_lossy(&data).trim().to_owned(); 
+        Ok(self)
+    }
+
+    /// Sets the background color used to highlight selected rows.
+    ///
+    /// # Parameters
+    /// - `color`: The highlight color. Defaults to `Colors::CyanBack`.
+    ///
+    /// # Returns
+    /// `DocumentBuilder`: Returns self.
+    ///
+    /// # Example
+    /// ```rust
+    /// DocumentBuilder::new()
+    ///     .selection_highlight(Colors::YellowBack);
+    /// ```
+    pub fn selection_highlight(mut self, color: Colors) -> Self {
+        self.document.highlight = color;
+        self
+    }
+
+    /// Draws a scrollbar on the rightmost column, showing where the visible
+    /// rows are in the content. The column is taken from the Document.
+    ///
+    /// # Returns
+    /// `DocumentBuilder`: Returns `self`.
+    ///
+    /// # Example
+    /// ```rust
+    /// DocumentBuilder::new()
+    ///     .scrollbar();
+    /// ```
+    pub fn scrollbar(mut self) -> Self {
+        self.document.scrollbar = Some(Scrollbar::default());
+        self
+    }
+
+    /// Same as `scrollbar`, with the given characters instead of `│` for the
+    /// track and `█` for the thumb.
+    ///
+    /// # Parameters
+    /// - `track`: The character drawn along the scrollbar.
+    /// - `thumb`: The character showing the visible rows.
+    ///
+    /// # Returns
+    /// `DocumentBuilder`: Returns `self`.
+    ///
+    /// # Example
+    /// ```rust
+    /// // For terminals without box drawing characters.
+    /// DocumentBuilder::new()
+    ///     .scrollbar_chars('|', '#');
+    /// ```
+    pub fn scrollbar_chars(mut self, track: char, thumb: char) -> Self {
+        self.document.scrollbar = Some(Scrollbar::new(track, thumb));
+        self
+    }
+
+    /// Sets the keys `Document::handle_key` responds to.
+    ///
+    /// # Parameters
+    /// - `keys`: The `ScrollKeys` bindings. Defaults to `ScrollKeys::Both`.
+    ///
+    /// # Returns
+    /// `DocumentBuilder`: Returns self.
+    ///
+    /// # Example
+    /// ```rust
+    /// // Leave the letter keys free for other commands.
+    /// DocumentBuilder::new()
+    ///     .content(...)
+    ///     .scroll_keys(ScrollKeys::Arrows);
+    /// ```
+    pub fn scroll_keys(mut self, keys: ScrollKeys) -> Self {
+        self.document.scroll_keys = keys;
+        self
+    }
+
+    /// Sets how the lines of the `Document` are broken into rows.
+    ///
+    /// # Parameters
+    /// - `strategy`: A boxed `WrapStrategy`. Defaults to `CharWrap`.
+    ///
+    /// # Returns
+    /// `DocumentBuilder`: Returns self.
+    ///
+    /// # Example
+    /// ```rust
+    /// // Wrap prose between words.
+    /// DocumentBuilder::new()
+    ///     .content(...)
+    ///     .wrap_strategy(Box::new(WordWrap));
+    ///
+    /// // Keep log lines on one row.
+    /// DocumentBuilder::new()
+    ///     .content(...)
+    ///     .wrap_strategy(Box::new(Truncate));
+    /// ```
+    pub fn wrap_strategy(mut self, strategy: Box<dyn WrapStrategy>) -> Self {
+        self.document.wrapper = Wrapper::new(strategy);
+        self.document.row_count.clear();
+        self
+    }
+
+    /// Shorthand for wrapping between words with `WordWrap`, or going back to
+    /// cutting at the width with `CharWrap`. Words wider than the `Renderer`
+    /// are still cut.
+    ///
+    /// # Parameters
+    /// - `enable`: Whether to wrap between words. Defaults to `false`.
+    ///
+    /// # Returns
+    /// `DocumentBuilder`: Returns self.
+    ///
+    /// # Example
+    /// ```rust
+    /// DocumentBuilder::new()
+    ///     .content(...)
+    ///     .word_wrap(true);
+    /// ```
+    pub fn word_wrap(mut self, enable: bool) -> Self {
+        self.document.wrapper = if enable { Wrapper::Word } else { Wrapper::Char };
+        self.document.row_count.clear();
+        self
+    }
+
     /// Renders the current `Document` directly to the terminal without
     /// creating and returning a new one.
     ///
@@ -277,15 +781,18 @@ impl DocumentBuilder {
 
 impl RenderableMut<Renderer> for Document {
     fn render(&mut self, renderer: &mut Renderer) -> FtuiResult<()> {
-        let len = self.data.len();
         let (width, height) = renderer.get_dimensions();
-        let wrap_n = (len as f64 / width as f64).ceil() as usize;
-        let width = width as usize;
+        // The text must not run into the scrollbar.
+        let width = if self.scrollbar.is_some() { width.saturating_sub(1) } else { width };
+        let rows = self.rows(width as usize);
         let height = height as usize;
+        self.last_width = Some(width as usize);
+        self.row_count.set(width as usize, rows.len());
         let skip_top = if self.header.is_some() { 1 } else { 0 };
         let skip_bottom = if self.footer.is_some() { 1 } else { 0 };
         let max_lines = (height - 1) - skip_bottom;
-        self.offset_ensure_in_bound(wrap_n - 1);
+        self.viewport = max_lines;
+        self.ensure_in_bound(rows.len(), max_lines);
 
         renderer.clear();
 
@@ -293,19 +800,71 @@ impl RenderableMut<Renderer> for Document {
             header.render(renderer)?;
         }
 
-        for i in (0..wrap_n - self.offset).take(max_lines) {
-            let line = renderer.line_mut(i + skip_top);
-            let begin = (i + self.offset) * width;
-            let end = (begin + len.min(width)).min(len);
+        for (i, row) in rows
+            .iter()
+            .enumerate()
+            .skip(self.offset)
+            .take(max_lines) 
+        {
+            let line = renderer.line_mut(i - self.offset + skip_top);
+
+            // Clip between graphemes, so a character never loses the marks
+            // combined with it.
+            let mut used = 0;
+            let cells = row
+                .graphemes(true)
+                .take_while(|grapheme| {
+                    used += grapheme.chars().count();
+                    used <= width as usize
+                })
+                .flat_map(str::chars);
 
-            line.edit(&self.data[begin..end], 0);
+            line.edit_iter(cells, 0);
             line.add_ansi_many(&self.style);
+
+            if self.is_selected(i) {
+                line.add_ansi(self.highlight.to_ansi());
+            }
+        }
+
+        if let Some(scrollbar) = &self.scrollbar {
+            scrollbar.render(renderer, skip_top, max_lines, self.offset, rows.len());
         }
 
         if let Some(footer) = &mut self.footer {
-            renderer.render_text_as_footer(footer)?;
+            renderer.render_text_as_footer(footer, OverflowPolicy::Error)?;
         }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WIDE: &str = "日本語のテキストです\n👨‍👩‍👧 family e\u{301}te\u{301}\n\n한국어 텍스트";
+
+    #[test]
+    fn wide_content_does_not_panic() {
+        for width in 1..=12 {
+            let mut renderer = Renderer::headless(width, 6);
+            let mut doc = DocumentBuilder::new().content(WIDE).build();
+
+            renderer.render(&mut doc).unwrap();
+            while doc.scroll_down() {
+                renderer.render(&mut doc).unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn wide_content_wraps_without_clipping() {
+        let mut renderer = Renderer::headless(4, 8);
+        let mut doc = DocumentBuilder::new().content("日本語のテキスト").build();
+
+        let frame = renderer.render_to_plain_string(&mut doc).unwrap();
+
+        assert!(frame.starts_with("日本語の\nテキスト\n"));
+    }
+}
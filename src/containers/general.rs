@@ -1,12 +1,81 @@
+use unicode_segmentation::UnicodeSegmentation;
+
 use crate::components as cpn;
 use crate::error::FtuiResult;
 use crate::error::FtuiError;
 use crate::renderer::Renderer;
+use crate::util;
+use crate::util::ansi::Ansi;
 use crate::util::Colors;
+use crate::util::OverflowPolicy;
+use crate::util::VerticalAlign;
 use crate::util::id::IdGenerator;
 use crate::util::id::GeneratedId;
 use crate::util::RenderableMut;
-use crate::util::Renderable;
+
+/// The kind of component on each line of a `General`, in the order the
+/// components were added. The header is not included, it always comes first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Slot {
+    Option,
+    Text,
+    Separator,
+}
+
+/// How many rows the components of a `General` take in the current
+/// `Renderer`. Only wrapped labels take more than one.
+struct Rows {
+    header: u16,
+    options: Vec<u16>,
+    texts: Vec<u16>,
+}
+
+/// A footer made of a left-aligned and a right-aligned segment sharing the
+/// last line, like a status bar.
+#[derive(Debug, PartialEq, Eq)]
+struct SplitFooter {
+    left: String,
+    right: String,
+    style: Vec<Ansi>,
+}
+
+impl SplitFooter {
+    /// Returns how many graphemes of each segment fit in `width` columns,
+    /// keeping at least one column between them. The longer segment is cut
+    /// first.
+    fn fit(&self, width: usize) -> (usize, usize) {
+        let mut left = self.left.graphemes(true).count();
+        let mut right = self.right.graphemes(true).count();
+        let budget = width.saturating_sub(1);
+
+        if left + right > budget {
+            let half = budget / 2;
+
+            if left > right {
+                right = right.min(half);
+                left = budget - right;
+            } else {
+                left = left.min(half);
+                right = budget - left;
+            }
+        }
+
+        (left, right)
+    }
+
+    fn render(&self, renderer: &mut Renderer) {
+        let (width, height) = renderer.get_dimensions();
+        let (left_len, right_len) = self.fit(width as usize);
+        let left = util::truncate(&self.left, left_len);
+        let right = util::truncate(&self.right, right_len);
+
+        let line = renderer.line_mut(height as usize - 1);
+
+        line.edit(&left, 0);
+        line.edit(&right, width - right.chars().count() as u16);
+        line.add_ansi_many(&self.style);
+    }
+}
 
 /// A general container used to store and organize UI components,
 /// including `Header`, `Option`, `Text`, and `Separator`. It is created using
@@ -24,10 +93,17 @@ pub struct General {
     id_generator: IdGenerator,
     header: Option<cpn::Text>,
     footer: Option<cpn::Text>,
+    split_footer: Option<SplitFooter>,
     options: cpn::OptionsManager,
     texts: cpn::TextsManager,
     separators: Vec<cpn::Separator>,
+    slots: Vec<Slot>,
     component_count: u16,
+    overflow: OverflowPolicy,
+    vertical_align: VerticalAlign,
+    zebra: Option<(Colors, Colors)>,
+    default_flags: Option<cpn::TextFlags>,
+    control_chars: cpn::ControlChars,
 }
 
 impl General {
@@ -45,10 +121,17 @@ impl General {
             id_generator: IdGenerator::new(),
             header: None,
             footer: None,
+            split_footer: None,
             options: cpn::OptionsManager::new(),
             texts: cpn::TextsManager::new(),
             separators: vec![],
+            slots: vec![],
             component_count: 0,
+            overflow: OverflowPolicy::Error,
+            vertical_align: VerticalAlign::Top,
+            zebra: None,
+            default_flags: None,
+            control_chars: cpn::ControlChars::default(),
         }
     }
 
@@ -60,19 +143,26 @@ impl General {
     #[inline]
     pub(crate) fn set_footer(&mut self, footer: cpn::Text) {
         self.footer = Some(footer);
+        self.split_footer = None;
+    }
+
+    fn set_split_footer(&mut self, footer: SplitFooter) {
+        self.split_footer = Some(footer);
+        self.footer = None;
+    }
+
+    #[inline]
+    fn has_footer(&self) -> bool {
+        self.footer.is_some() || self.split_footer.is_some()
     }
 
     // Return added Option ID.
     pub(crate) fn add_option(&mut self, mut option: cpn::Option) -> GeneratedId {
         let id = self.id_generator.get_id();
         option.set_id(id);
-        option.set_line(self.component_count);
-
-        if self.options.comps().is_empty() {
-            option.set_selc_on(true);
-        }
 
         self.options.add(option);
+        self.slots.push(Slot::Option);
         self.component_count += 1;
 
         id
@@ -82,23 +172,246 @@ impl General {
     pub(crate) fn add_text(&mut self, mut text: cpn::Text) -> GeneratedId {
         let id = self.id_generator.get_id();
         text.set_id(id);
-        text.set_line(self.component_count);
 
         self.texts.add(text);
+        self.slots.push(Slot::Text);
         self.component_count += 1;
 
         id
     }
 
-    pub(crate) fn add_separator(&mut self, mut separator: cpn::Separator) {
-        separator.set_line(self.component_count);
+    /// Inserts an `Option` labeled `label` at `index` among the `Option`s,
+    /// or after the last one if `index` is past it. The `Selector` stays on
+    /// the `Option` it was on.
+    ///
+    /// # Parameters
+    /// - `index`: The position among the `Option`s, `Text`s and `Separator`s
+    ///   are not counted.
+    /// - `label`: The text displayed for the option.
+    ///
+    /// # Returns
+    /// `GeneratedId`: The ID of the inserted `Option`.
+    ///
+    /// # Example
+    /// ```rust
+    /// // A device was plugged in, list it first.
+    /// let id = container.insert_option(0, device.name());
+    /// ```
+    pub fn insert_option(&mut self, index: usize, label: impl ToString) -> GeneratedId {
+        let id = self.id_generator.get_id();
+        let mut option = cpn::Option::new(label);
+        option.set_id(id);
+
+        let index = self.options.insert_at(index, option);
+
+        // Goes before the `Option` it was inserted before, or right after the
+        // last one.
+        let slot = self.option_slot(index)
+            .or_else(|| index.checked_sub(1).and_then(|i| self.option_slot(i)).map(|i| i + 1))
+            .unwrap_or(self.slots.len());
+
+        self.slots.insert(slot, Slot::Option);
+        self.component_count += 1;
+
+        id
+    }
+
+    /// Removes the `Option` with the given ID. If the `Selector` was on it,
+    /// it moves to the next enabled `Option`, or the previous one at the end.
+    ///
+    /// # Parameters
+    /// - `id`: The ID of the `Option` to remove.
+    ///
+    /// # Returns
+    /// - `Ok(())`: Returns nothing.
+    /// - `Err(FtuiError)`: No `Option` with the given ID exists.
+    ///
+    /// # Example
+    /// ```rust
+    /// // The device was unplugged.
+    /// container.remove_option(device_id)?;
+    /// ```
+    pub fn remove_option(&mut self, id: GeneratedId) -> FtuiResult<()> {
+        let index = self.options.remove(id)?;
+
+        if let Some(slot) = self.option_slot(index) {
+            self.slots.remove(slot);
+        }
+        self.component_count -= 1;
+
+        Ok(())
+    }
+
+    /// Returns the position in `slots` of the `Option` at `index`.
+    fn option_slot(&self, index: usize) -> Option<usize> {
+        self.slots
+            .iter()
+            .enumerate()
+            .filter(|(_, slot)| **slot == Slot::Option)
+            .nth(index)
+            .map(|(i, _)| i)
+    }
+
+    pub(crate) fn add_separator(&mut self, separator: cpn::Separator) {
         self.separators.push(separator);
+        self.slots.push(Slot::Separator);
         self.component_count += 1;
     }
 
+    /// Assigns a line to every component, starting at `first_line`. The header
+    /// comes first, then the other components in the order they were added.
+    /// `option_rows` holds how many rows each `Option` takes.
+    fn layout(&mut self, first_line: u16, rows: &Rows) {
+        let mut line = first_line;
+        let mut option_rows = rows.options.iter();
+        let mut text_rows = rows.texts.iter();
+
+        if let Some(header) = &mut self.header {
+            header.set_line(line);
+            line += rows.header;
+        }
+
+        let mut options = self.options.comps_mut().iter_mut();
+        let mut texts = self.texts.comps_mut().iter_mut();
+        let mut separators = self.separators.iter_mut();
+
+        for slot in &self.slots {
+            line += match slot {
+                Slot::Option => {
+                    if let Some(option) = options.next() {
+                        option.set_line(line);
+                    }
+                    option_rows.next().copied().unwrap_or(1)
+                }
+                Slot::Text => {
+                    let rows = text_rows.next().copied().unwrap_or(1);
+
+                    match texts.next() {
+                        // Dropped optional texts take no line.
+                        Some(text) if text.is_hidden() => 0,
+                        Some(text) => {
+                            text.set_line(line);
+                            rows
+                        }
+                        None => rows,
+                    }
+                }
+                Slot::Separator => {
+                    if let Some(separator) = separators.next() {
+                        separator.set_line(line);
+                    }
+                    1
+                }
+            };
+        }
+    }
+
+    /// Hides optional `Text`s, lowest priority first, until `rows` fits in
+    /// `height`. Among equal priorities the last added is hidden first.
+    /// `text_rows` are the rows each `Text` takes.
+    ///
+    /// # Returns
+    /// `u16`: The rows left once the hidden `Text`s are removed.
+    fn drop_optional(&mut self, mut rows: u16, height: u16, text_rows: &[u16]) -> u16 {
+        let texts = self.texts.comps_mut();
+        texts.iter_mut().for_each(|text| text.set_hidden(false));
+
+        let mut optional: Vec<(u32, usize)> = texts
+            .iter()
+            .enumerate()
+            .filter_map(|(i, text)| text.priority().map(|priority| (priority, i)))
+            .collect();
+        optional.sort_by_key(|&(priority, i)| (priority, std::cmp::Reverse(i)));
+
+        for (_, i) in optional {
+            if rows <= height {
+                break;
+            }
+
+            texts[i].set_hidden(true);
+            rows -= text_rows.get(i).copied().unwrap_or(1);
+        }
+
+        rows
+    }
+
+    /// Removes every component, header and footer from the `General`, leaving
+    /// it empty as if freshly built. IDs start over, so IDs from before the
+    /// call must not be used afterwards. Settings such as the overflow policy,
+    /// alignment and selection style are kept, and the memory is reused.
+    ///
+    /// # Example
+    /// ```rust
+    /// // Switch to another screen without allocating a new container.
+    /// container.clear_all();
+    ///
+    /// let container = GeneralBuilder::reuse(container)
+    ///     .header("Settings", None)?
+    ///     .option("Back")
+    ///     .build();
+    /// ```
+    pub fn clear_all(&mut self) {
+        self.id_generator = IdGenerator::new();
+        self.header = None;
+        self.footer = None;
+        self.split_footer = None;
+        self.options.clear();
+        self.texts.clear();
+        self.separators.clear();
+        self.slots.clear();
+        self.component_count = 0;
+    }
+
+    /// Returns, for every `Separator`, whether an `Option` or a shown `Text`
+    /// comes both before and after it.
+    fn separators_between(&self) -> Vec<bool> {
+        let mut texts = self.texts.comps().iter();
+        let is_component: Vec<bool> = self.slots
+            .iter()
+            .map(|slot| match slot {
+                Slot::Option => true,
+                Slot::Text => texts.next().is_some_and(|text| !text.is_hidden()),
+                Slot::Separator => false,
+            })
+            .collect();
+        let first = is_component.iter().position(|is| *is);
+        let last = is_component.iter().rposition(|is| *is);
+
+        self.slots
+            .iter()
+            .enumerate()
+            .filter(|(_, slot)| **slot == Slot::Separator)
+            .map(|(i, _)| matches!((first, last), (Some(first), Some(last)) if first < i && i < last))
+            .collect()
+    }
+
+    /// Returns the `Option` the `Selector` is on, to show what is selected
+    /// without tracking it separately.
+    ///
+    /// # Returns
+    /// - `Some(&Option)`: A reference to the selected `Option` component.
+    /// - `None`: No `Option` is selected, or the `General` has none.
+    ///
+    /// # Example
+    /// ```rust
+    /// container.options_mut().selector_down();
+    ///
+    /// if let Some(option) = container.selected_option() {
+    ///     status.set_label(format!("Selected: {}", option.label()));
+    /// }
+    /// ```
+    #[inline]
+    pub fn selected_option(&self) -> Option<&cpn::Option> {
+        self.options.selected()
+    }
+
     pub fn options_mut(&mut self) -> &mut cpn::OptionsManager {
         &mut self.options
     }
+
+    pub fn texts_mut(&mut self) -> &mut cpn::TextsManager {
+        &mut self.texts
+    }
 }
 
 /// `GeneralBuilder` is used to create `General` instances using the builder
@@ -143,6 +456,26 @@ impl GeneralBuilder {
         GeneralBuilder { container: General::new(), }
     }
 
+    /// Constructs a `GeneralBuilder` that builds onto an existing `General`
+    /// after emptying it with `clear_all`, reusing its memory and settings.
+    ///
+    /// # Parameters
+    /// - `container`: The `General` to reuse.
+    ///
+    /// # Return
+    /// `GeneralBuilder`: A new instance of `GeneralBuilder`.
+    ///
+    /// # Example
+    /// ```rust
+    /// let container = GeneralBuilder::reuse(container)
+    ///     .option("Back")
+    ///     .build();
+    /// ```
+    pub fn reuse(mut container: General) -> Self {
+        container.clear_all();
+        GeneralBuilder { container }
+    }
+
     /// Sets the header for the `General`.
     ///
     /// # Notes
@@ -167,7 +500,8 @@ impl GeneralBuilder {
     pub fn header(
         mut self, label: impl ToString, flags: impl Into<Option<cpn::TextFlags>>
     ) -> FtuiResult<Self> {
-        self.container.set_header(cpn::Text::new(label, flags)?);
+        let control = self.container.control_chars;
+        self.container.set_header(cpn::Text::new_with_control(label, flags, control)?);
         Ok(self)
     }
 
@@ -195,7 +529,48 @@ impl GeneralBuilder {
     pub fn footer(
         mut self, label: impl ToString, flags: impl Into<Option<cpn::TextFlags>>
     ) -> FtuiResult<Self> {
-        self.container.set_footer(cpn::Text::new(label, flags)?);
+        let control = self.container.control_chars;
+        self.container.set_footer(cpn::Text::new_with_control(label, flags, control)?);
+        Ok(self)
+    }
+
+    /// Sets a footer with two segments on the last line: one at the left edge
+    /// and one at the right edge, like a status bar. This replaces a footer
+    /// set with `footer`, and the other way around.
+    ///
+    /// # Notes
+    /// - When both segments do not fit, the longer one is cut with `…` so they
+    ///   never overlap.
+    /// - Alignment flags are ignored.
+    ///
+    /// # Parameters
+    /// - `left`: The left-aligned segment, for example key hints.
+    /// - `right`: The right-aligned segment, for example a status.
+    /// - `flags`: An optional set of `TextFlags` styling the whole line.
+    ///
+    /// # Returns
+    /// - `Ok(GeneralBuilder)`: Returns `self`.
+    /// - `Err(FtuiError)`: Returns an error.
+    ///
+    /// # Example
+    /// ```rust
+    /// // `q: quit  ↑↓: move                     3 items`
+    /// GeneralBuilder::new()
+    ///     .footer_split("q: quit  ↑↓: move", "3 items", TextFlags::STYLE_DIM)?;
+    /// ```
+    pub fn footer_split(
+        mut self,
+        left: impl ToString, right: impl ToString,
+        flags: impl Into<Option<cpn::TextFlags>>,
+    ) -> FtuiResult<Self> {
+        let flags = flags.into().unwrap_or(cpn::TextFlags::NONE);
+        flags.ensure_compatibility()?;
+
+        self.container.set_split_footer(SplitFooter {
+            left: left.to_string(),
+            right: right.to_string(),
+            style: flags.resolve_ansi(),
+        });
         Ok(self)
     }
 
@@ -247,9 +622,136 @@ impl GeneralBuilder {
         self
     }
 
+    /// Adds a disabled `Option` component to the `General`. It is shown like
+    /// any other `Option`, but the `Selector` skips over it and it cannot be
+    /// selected.
+    ///
+    /// # Parameters
+    /// - `label`: The text displayed for this option.
+    ///
+    /// # Returns
+    /// `GeneralBuilder`: Returns `self`.
+    ///
+    /// # Example
+    /// ```rust
+    /// // A section label the `Selector` never lands on.
+    /// GeneralBuilder::new()
+    ///     .option_disabled("-- Audio --")
+    ///     .option("Volume")
+    ///     .option("Mute");
+    /// ```
+    #[inline]
+    pub fn option_disabled(mut self, label: impl ToString) -> Self {
+        let mut option = cpn::Option::new(label);
+        option.set_disabled(true);
+
+        self.container.add_option(option);
+        self
+    }
+
+    /// Sets which `Option` the `Selector` starts on instead of the first one.
+    /// Only one `Option` is ever selected, so calling this again moves the
+    /// starting selection.
+    ///
+    /// # Parameters
+    /// - `id`: The ID of an `Option` that has already been added.
+    ///
+    /// # Returns
+    /// - `Ok(GeneralBuilder)`: Returns `self`.
+    /// - `Err(FtuiError)`: No `Option` with the given ID exists.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut id = 0;
+    ///
+    /// // The `Selector` starts on "Medium" rather than "Low".
+    /// GeneralBuilder::new()
+    ///     .option("Low")
+    ///     .option_id("Medium", &mut id)
+    ///     .option("High")
+    ///     .default_selection(id)?;
+    /// ```
+    pub fn default_selection(mut self, id: GeneratedId) -> FtuiResult<Self> {
+        if self.container.options_mut().set_selector_on(id) {
+            Ok(self)
+        } else {
+            Err(FtuiError::ContainerNoComponentById)
+        }
+    }
+
+    /// Starts the `General` with no `Option` selected instead of the first one.
+    /// No `Option` is highlighted until the `Selector` is moved, and the first
+    /// move up or down selects the first `Option`. Until then,
+    /// `selector_select` does nothing and returns `false`.
+    ///
+    /// # Returns
+    /// - `GeneralBuilder`: Returns `self`.
+    ///
+    /// # Example
+    /// ```rust
+    /// // Require the user to navigate before anything can be selected.
+    /// GeneralBuilder::new()
+    ///     .option("Delete everything")
+    ///     .option("Cancel")
+    ///     .no_default_selection();
+    /// ```
+    #[inline]
+    pub fn no_default_selection(mut self) -> Self {
+        self.container.options_mut().disable_default_selection();
+        self
+    }
+
+    /// Sets the color used to highlight the selected `Option`. Shortcut for
+    /// `selection_style(SelectionStyle::Color(color))`.
     #[inline]
     pub fn option_highligh(mut self, color: Colors) -> Self {
-        self.container.options_mut().set_highlight(color);
+        self.container.options_mut().set_selection_style(cpn::SelectionStyle::Color(color));
+        self
+    }
+
+    /// Wraps `Option` labels that are too wide for the `Renderer` onto more
+    /// rows instead of applying the overflow policy. The rows after the first
+    /// are indented, and the selection covers all of them. Navigation still
+    /// treats a wrapped `Option` as one item.
+    ///
+    /// # Parameters
+    /// - `wrap`: Whether to wrap long labels. Defaults to `false`.
+    ///
+    /// # Returns
+    /// - `GeneralBuilder`: Returns `self`.
+    ///
+    /// # Example
+    /// ```rust
+    /// // `Download the latest update and`
+    /// // `  restart`
+    /// GeneralBuilder::new()
+    ///     .option("Download the latest update and restart")
+    ///     .option_wrap(true);
+    /// ```
+    #[inline]
+    pub fn option_wrap(mut self, wrap: bool) -> Self {
+        self.container.options_mut().set_wrap(wrap);
+        self
+    }
+
+    /// Sets how the selected `Option` is shown.
+    ///
+    /// # Parameters
+    /// - `style`: The `SelectionStyle` to use. Defaults to
+    ///   `SelectionStyle::Color(Colors::CyanBack)`.
+    ///
+    /// # Returns
+    /// - `GeneralBuilder`: Returns `self`.
+    ///
+    /// # Example
+    /// ```rust
+    /// // Follow the terminal's theme instead of a fixed color.
+    /// GeneralBuilder::new()
+    ///     .selection_style(SelectionStyle::Reverse);
+    /// ```
+    #[inline]
+    pub fn selection_style(mut self, style: cpn::SelectionStyle) -> Self {
+        self.container.options_mut().set_selection_style(style);
         self
     }
 
@@ -277,7 +779,67 @@ impl GeneralBuilder {
     pub fn text(
         mut self, label: impl ToString, flags: impl Into<Option<cpn::TextFlags>>
     ) -> FtuiResult<Self> {
-        self.container.add_text(cpn::Text::new(label, flags)?);
+        let text = self.new_text(label, flags)?;
+        self.container.add_text(text);
+        Ok(self)
+    }
+
+    /// Adds a `Text` component whose label is already styled with ANSI
+    /// escapes, such as colored output from another tool. Only the visible
+    /// text is measured, so alignment is not thrown off by the escapes.
+    ///
+    /// # Parameters
+    /// - `label`: The text to display, with ANSI escapes.
+    /// - `flags`: A set of `TextFlags`, combined using the bitwise OR operator.
+    ///
+    /// # Returns
+    /// - `Ok(GeneralBuilder)`: Returns `self`.
+    /// - `Err(FtuiError)`: Returns an error.
+    ///
+    /// # Example
+    /// ```rust
+    /// GeneralBuilder::new()
+    ///     .text_prestyled("Build: \x1b[32mpassing\x1b[0m", TextFlags::ALIGN_MIDDLE)?;
+    /// ```
+    pub fn text_prestyled(
+        mut self, label: &str, flags: impl Into<Option<cpn::TextFlags>>
+    ) -> FtuiResult<Self> {
+        let flags = flags.into().or(self.container.default_flags);
+        let control = self.container.control_chars;
+        self.container.add_text(cpn::Text::new_prestyled(label, flags, control)?);
+        Ok(self)
+    }
+
+    /// Adds a `Text` component that is only shown when there is room for it.
+    /// When the `General` is taller than the `Renderer`, optional `Text`s are
+    /// hidden, lowest `priority` first, until it fits, instead of failing
+    /// with `FtuiError::RendererContainerTooBig`. Hidden `Text`s take no line.
+    ///
+    /// # Parameters
+    /// - `label`: A `&str` representing the text to display.
+    /// - `flags`: A set of `TextFlags`, combined using the bitwise OR operator.
+    /// - `priority`: Higher priorities are kept longer.
+    ///
+    /// # Returns
+    /// - `Ok(GeneralBuilder)`: Returns `self`.
+    /// - `Err(FtuiError)`: Returns an error.
+    ///
+    /// # Example
+    /// ```rust
+    /// // The key hints are dropped first on small terminals.
+    /// GeneralBuilder::new()
+    ///     .option("Start")
+    ///     .option("Quit")
+    ///     .text_optional("Press Enter to select", None, 1)?
+    ///     .text_optional("Use the arrow keys to move", None, 0)?;
+    /// ```
+    pub fn text_optional(
+        mut self, label: impl ToString, flags: impl Into<Option<cpn::TextFlags>>, priority: u32
+    ) -> FtuiResult<Self> {
+        let mut text = self.new_text(label, flags)?;
+        text.set_priority(priority);
+
+        self.container.add_text(text);
         Ok(self)
     }
 
@@ -312,10 +874,166 @@ impl GeneralBuilder {
         label: impl ToString,
         flags: impl Into<Option<cpn::TextFlags>>, store_id: &mut GeneratedId
     ) -> FtuiResult<Self> {
-        *store_id = self.container.add_text(cpn::Text::new(label, flags)?);
+        let text = self.new_text(label, flags)?;
+        *store_id = self.container.add_text(text);
+        Ok(self)
+    }
+
+    /// Sets what happens when a label in the `General` is wider than the
+    /// `Renderer`. This applies to the header, footer, `Option`s and `Text`s.
+    ///
+    /// # Notes
+    /// - With `OverflowPolicy::Wrap`, wrapped labels take more lines, and the
+    ///   `General` must still fit the `Renderer`. Wrapped `Option` rows are
+    ///   indented like with `option_wrap`, and a split footer is cut instead.
+    ///
+    /// # Parameters
+    /// - `policy`: The `OverflowPolicy` to use. Defaults to `OverflowPolicy::Error`.
+    ///
+    /// # Returns
+    /// - `GeneralBuilder`: Returns `self`.
+    ///
+    /// # Example
+    /// ```rust
+    /// // Cut over-wide labels with `…` instead of failing to render.
+    /// GeneralBuilder::new()
+    ///     .overflow(OverflowPolicy::Truncate);
+    /// ```
+    #[inline]
+    pub fn overflow(mut self, policy: OverflowPolicy) -> Self {
+        self.container.overflow = policy;
+        self
+    }
+
+    /// Keeps an index from component IDs to positions, making `query` and
+    /// `query_mut` on the `Option`s and `Text`s `O(1)` lookups instead of
+    /// linear scans. Only worth it for containers with many components.
+    ///
+    /// # Returns
+    /// - `GeneralBuilder`: Returns `self`.
+    ///
+    /// # Example
+    /// ```rust
+    /// GeneralBuilder::new()
+    ///     .indexed();
+    /// ```
+    #[inline]
+    pub fn indexed(mut self) -> Self {
+        self.container.options.enable_index();
+        self.container.texts.enable_index();
+        self
+    }
+
+    /// Sets where the block of components sits when the `Renderer` is taller
+    /// than it. The whole block, header included, is moved as one; the footer
+    /// always stays on the last line. This is separate from the alignment of
+    /// each `Text` within its own line.
+    ///
+    /// # Parameters
+    /// - `align`: The `VerticalAlign` to use. Defaults to `VerticalAlign::Top`.
+    ///
+    /// # Returns
+    /// - `GeneralBuilder`: Returns `self`.
+    ///
+    /// # Example
+    /// ```rust
+    /// // A short menu centered on a big screen.
+    /// GeneralBuilder::new()
+    ///     .option("Play")
+    ///     .option("Quit")
+    ///     .vertical_align(VerticalAlign::Center);
+    /// ```
+    #[inline]
+    pub fn vertical_align(mut self, align: VerticalAlign) -> Self {
+        self.container.vertical_align = align;
+        self
+    }
+
+    /// Sets the default `TextFlags` of the `Text` components added to the
+    /// `General` without flags, to theme a whole container at once. `Text`s
+    /// added with flags keep their own. The header and footer are not
+    /// affected.
+    ///
+    /// # Notes
+    /// - Only `Text`s added after this call get the default.
+    ///
+    /// # Parameters
+    /// - `flags`: The `TextFlags` to apply to `Text`s unless explicitly overridden.
+    ///
+    /// # Returns
+    /// - `Ok(GeneralBuilder)`: Returns `self`.
+    /// - `Err(FtuiError)`: Returns an error.
+    ///
+    /// # Example
+    /// ```rust
+    /// GeneralBuilder::new()
+    ///     .default_flags(TextFlags::COLOR_CYAN)?
+    ///     .text("Cyan", None)?
+    ///     .text("Red", TextFlags::COLOR_RED)?;
+    /// ```
+    pub fn default_flags(mut self, flags: cpn::TextFlags) -> FtuiResult<Self> {
+        flags.ensure_compatibility()?;
+        self.container.default_flags = Some(flags);
         Ok(self)
     }
 
+    /// Sets how control characters such as `\r` or `\0` in the labels of the
+    /// header, footer and `Text`s are handled. They are removed by default.
+    ///
+    /// # Notes
+    /// - Only labels added after this call are affected.
+    ///
+    /// # Parameters
+    /// - `control`: The `ControlChars` to use. Defaults to `ControlChars::Strip`.
+    ///
+    /// # Returns
+    /// - `GeneralBuilder`: Returns `self`.
+    ///
+    /// # Example
+    /// ```rust
+    /// // Show untrusted labels with `�` in place of control characters.
+    /// GeneralBuilder::new()
+    ///     .control_chars(ControlChars::Replace)
+    ///     .text(file_name, None)?;
+    /// ```
+    #[inline]
+    pub fn control_chars(mut self, control: cpn::ControlChars) -> Self {
+        self.container.control_chars = control;
+        self
+    }
+
+    /// Creates a `Text` with `flags`, or the default flags if there are none.
+    fn new_text(
+        &self, label: impl ToString, flags: impl Into<Option<cpn::TextFlags>>
+    ) -> FtuiResult<cpn::Text> {
+        let flags = flags.into().or(self.container.default_flags);
+        cpn::Text::new_with_control(label, flags, self.container.control_chars)
+    }
+
+    /// Stripes the rows below the header with two alternating background
+    /// colors, making dense menus easier to scan. The selection highlight and
+    /// the colors of each component are drawn over the stripes.
+    ///
+    /// # Parameters
+    /// - `even`: The background color of the first row and every other row.
+    /// - `odd`: The background color of the rows in between.
+    ///
+    /// # Returns
+    /// - `GeneralBuilder`: Returns `self`.
+    ///
+    /// # Example
+    /// ```rust
+    /// GeneralBuilder::new()
+    ///     .option(...)
+    ///     .option(...)
+    ///     .zebra(Colors::BlackBack, Colors::BlueBack);
+    /// ```
+    #[inline]
+    pub fn zebra(mut self, even: Colors, odd: Colors) -> Self {
+        self.container.zebra = Some((even, odd));
+        self
+    }
+
     /// Add a standard (non-dotted) `Separator` with the given style.
     ///
     /// # Parameters
@@ -356,6 +1074,56 @@ impl GeneralBuilder {
         self
     }
 
+    /// Add a standard `Separator` ending in tee glyphs, so it joins side
+    /// borders drawn in the same style: `├────┤` for `Thin`, `┣━━━━┫` for
+    /// `Medium` and `╠════╣` for `Double`. Other styles have no caps.
+    ///
+    /// # Parameters
+    /// - `style`: The visual style of the separator, specified as a `SeparatorStyle`.
+    ///
+    /// # Returns
+    /// - `GeneralBuilder`: Returns `self`.
+    ///
+    /// # Example
+    /// ```rust
+    /// GeneralBuilder::new()
+    ///     .separator_capped(SeparatorStyle::Thin);
+    /// ```
+    #[inline]
+    pub fn separator_capped(mut self, style: cpn::SeparatorStyle) -> Self {
+        self.container.add_separator(cpn::Separator::normal(style).capped());
+        self
+    }
+
+    /// Add a contextual `Separator` with the given style. It is only drawn when
+    /// there is an `Option` or `Text` both above and below it, and its line is
+    /// left blank otherwise. Useful for screens assembled from optional parts.
+    ///
+    /// # Parameters
+    /// - `style`: The visual style of the separator, specified as a `SeparatorStyle`.
+    ///
+    /// # Returns
+    /// - `GeneralBuilder`: Returns `self`.
+    ///
+    /// # Example
+    /// ```rust
+    /// // The separator is only drawn if there were any recent files.
+    /// let mut builder = GeneralBuilder::new();
+    ///
+    /// for file in recent_files {
+    ///     builder = builder.option(file);
+    /// }
+    ///
+    /// builder
+    ///     .separator_contextual(SeparatorStyle::Thin)
+    ///     .option("Quit");
+    /// ```
+    #[inline]
+    pub fn separator_contextual(mut self, style: cpn::SeparatorStyle) -> Self {
+        self.container.add_separator(cpn::Separator::normal(style).contextual());
+        self
+    }
+
     /// Renders the current `General` directly to the terminal without
     /// creating and returning a new one.
     ///
@@ -402,27 +1170,70 @@ impl GeneralBuilder {
 
 impl RenderableMut<Renderer> for General {
     fn render(&mut self, renderer: &mut Renderer) -> FtuiResult<()> {
-        let (_, height) = renderer.get_dimensions();
+        let (width, height) = renderer.get_dimensions();
+        let component_rows = Rows {
+            header: self.header.as_ref().map_or(0, |header| header.row_count(width, self.overflow)),
+            options: self.options.row_counts(width as usize, self.overflow),
+            texts: self.texts.row_counts(width, self.overflow),
+        };
+        let footer_rows = match &self.footer {
+            Some(footer) => footer.row_count(width, self.overflow),
+            None if self.has_footer() => 1,
+            None => 0,
+        };
+
+        // Wrapped components take more than one row.
+        let extra = |rows: &[u16]| rows.iter().map(|rows| rows.saturating_sub(1)).sum::<u16>();
+        let rows = self.component_count
+            + component_rows.header.saturating_sub(1)
+            + extra(&component_rows.options)
+            + extra(&component_rows.texts);
 
-        if self.component_count > height {
+        // The footer takes the last lines, so the rest must fit above it.
+        let free_height = height.saturating_sub(footer_rows);
+        let rows = self.drop_optional(rows, free_height, &component_rows.texts);
+
+        if rows > free_height || footer_rows > height {
             return Err(FtuiError::RendererContainerTooBig);
         }
 
+        let first_line = self.vertical_align.offset(free_height, rows);
+        self.layout(first_line, &component_rows);
+
         renderer.clear();
 
+        // Components render after the stripes so their own colors, and the
+        // selection, take over.
+        if let Some((even, odd)) = self.zebra {
+            let body = first_line + component_rows.header;
+
+            for (i, line) in (body..first_line + rows).enumerate() {
+                let color = if i % 2 == 0 { even } else { odd };
+                renderer.line_mut(line as usize).add_ansi(color.to_ansi());
+            }
+        }
+
         if let Some(header) = &mut self.header {
-            header.render(renderer)?;
+            header.render_with(renderer, self.overflow)?;
         }
 
-        self.options.render(renderer)?;
-        self.texts.render(renderer)?;
+        self.options.render_with(renderer, self.overflow)?;
+        self.texts.render_with(renderer, self.overflow)?;
         
-        for seperator in self.separators.iter_mut() {
-            seperator.render(renderer)?;
+        let between = self.separators_between();
+
+        for (seperator, between) in self.separators.iter_mut().zip(between) {
+            if between || !seperator.is_contextual() {
+                seperator.render(renderer)?;
+            }
         }
 
         if let Some(footer) = &mut self.footer {
-            renderer.render_text_as_footer(footer)?;
+            renderer.render_text_as_footer(footer, self.overflow)?;
+        }
+
+        if let Some(footer) = &self.split_footer {
+            footer.render(renderer);
         }
 
         Ok(())
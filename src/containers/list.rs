@@ -1,11 +1,16 @@
 use crate::components::Text;
+use crate::containers::Scrollable;
+use crate::containers::scrollable::Scrollbar;
 use crate::components::TextFlags;
 use crate::error::FtuiError;
 use crate::error::FtuiResult;
 use crate::renderer::Renderer;
 use crate::util::id::IdGenerator;
 use crate::util::id::GeneratedId;
+use crate::util::id::IdIndex;
+use crate::util::ansi;
 use crate::util::number as num;
+use crate::util::OverflowPolicy;
 use crate::util::RenderableMut;
 
 /// A specialized variant of `Container` designed to display data in a vertical 
@@ -24,10 +29,28 @@ pub struct List {
     header: Option<Text>,
     footer: Option<Text>,
     elements: Vec<Text>,
+    index: IdIndex,
     offset: usize,
+    scroll_step: usize,
+    pinned: usize,
+    viewport: usize,
     default_flags: Option<TextFlags>,
     is_numbered: bool,
+    number_flags: Option<TextFlags>,
     id_generator: IdGenerator,
+    version: u64,
+    filter: Option<String>,
+    scrollbar: Option<Scrollbar>,
+    animation: Option<ScrollAnimation>,
+}
+
+/// A scroll of a `List` in progress, started by `scroll_to_index_animated`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ScrollAnimation {
+    from: usize,
+    target: usize,
+    frame: usize,
+    frames: usize,
 }
 
 impl List {
@@ -45,13 +68,52 @@ impl List {
             header: None,
             footer: None,
             elements: vec![],
+            index: IdIndex::default(),
             offset: 0,
+            scroll_step: 1,
+            pinned: 0,
+            viewport: 0,
             default_flags: None,
             is_numbered: false,
+            number_flags: None,
             id_generator: IdGenerator::new(),
+            version: 0,
+            filter: None,
+            scrollbar: None,
+            animation: None,
         }
     }
 
+    /// Returns a token that changes whenever the `List` is changed: elements
+    /// added, removed or accessed with `at_mut`, or the `List` scrolled.
+    /// Rendering does not change it. Compare it with the token from the last
+    /// draw to skip redrawing a `List` that did not change.
+    ///
+    /// # Returns
+    /// `u64`: The change token.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut drawn = None;
+    ///
+    /// loop {
+    ///     if drawn != Some(list.version()) {
+    ///         renderer.draw(&mut list)?;
+    ///         drawn = Some(list.version());
+    ///     }
+    ///     ...
+    /// }
+    /// ```
+    #[inline]
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    #[inline]
+    fn changed(&mut self) {
+        self.version = self.version.wrapping_add(1);
+    }
+
     /// Adds a new element to the `List`.
     ///
     /// # Parameters
@@ -81,6 +143,8 @@ impl List {
         let id = self.id_generator.get_id(); 
 
         self.elements.push(Text::with_id(label, flags.or(self.default_flags), id)?);
+        self.index.insert(id, self.elements.len() - 1);
+        self.changed();
         Ok(id)
     }
 
@@ -122,12 +186,60 @@ impl List {
         Ok(())
     }
 
+    /// Replaces every element of the `List` in one call. Each element gets a
+    /// new ID. If building any element fails, the `List` is left unchanged.
+    /// The scroll offset is clamped when the new content is shorter.
+    ///
+    /// # Parameters
+    /// - `elements`: An iterable collection of `(label, flags)` pairs.
+    ///
+    /// # Returns
+    /// - `Ok(())`: Returns nothing. 
+    /// - `Err(FtuiError)`: Returns an error.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut list = ListBuilder::new().build();
+    ///
+    /// // Refresh the list with new data.
+    /// list.set_elements(vec![
+    ///     ("Element1", None),
+    ///     ("Element2", Some(TextFlags::COLOR_RED)),
+    /// ])?;
+    /// ```
+    pub fn set_elements<T, F>(
+        &mut self, elements: impl IntoIterator<Item = (T, F)>
+    ) -> FtuiResult<()>
+    where
+        T: ToString,
+        F: Into<Option<TextFlags>>,
+    {
+        let elements = elements
+            .into_iter()
+            .map(|(label, flags)| {
+                let id = self.id_generator.get_id();
+                Text::with_id(label, flags.into().or(self.default_flags), id)
+            })
+            .collect::<FtuiResult<Vec<Text>>>()?;
+
+        self.elements = elements;
+        self.clamp_offset();
+        self.rebuild_index();
+        self.changed();
+
+        Ok(())
+    }
+
     #[inline]
     pub fn clear(&mut self) {
         self.elements.clear();
+        self.offset = 0;
+        self.animation = None;
+        self.rebuild_index();
+        self.changed();
     }
 
-    /// Attempts to scroll the `List` up by one position.
+    /// Attempts to scroll the `List` up by the scroll step (one by default).
     ///
     /// # Returns
     /// - `true` Ff the `List` was successfully scrolled up.
@@ -148,16 +260,12 @@ impl List {
     /// // Now it can scroll back up.
     /// assert_eq!(list.scroll_up(), true);
     /// ```
+    #[inline]
     pub fn scroll_up(&mut self) -> bool {
-        if self.offset != 0 {
-            self.offset -= 1;
-            true
-        } else {
-            false
-        }
+        Scrollable::scroll_up(self)
     }
 
-    /// Attempts to scroll the `List` down by one position.
+    /// Attempts to scroll the `List` down by the scroll step (one by default).
     ///
     /// # Returns
     /// - `true` if the list was successfully scrolled down.
@@ -175,13 +283,95 @@ impl List {
     /// // The list can scroll down since it's not at the bottom yet.
     /// assert_eq!(list.scroll_down(), true);
     /// ```
+    #[inline]
     pub fn scroll_down(&mut self) -> bool {
-        if self.offset < self.elements.len() - 1 {
-            self.offset += 1;
-            true
-        } else {
-            false
+        Scrollable::scroll_down(self)
+    }
+
+    /// Scrolls to the element at `i` over several frames instead of at once,
+    /// so long jumps are easier to follow. Each `tick` moves the offset one
+    /// frame closer, until it reaches `i` on the last frame.
+    ///
+    /// # Notes
+    /// - `i` counts the elements that scroll, like the offset of `Scrollable`,
+    ///   and is clamped to them.
+    /// - Any other scroll stops the animation where it is.
+    ///
+    /// # Parameters
+    /// - `i`: The element to scroll to.
+    /// - `frames`: Over how many `tick`s the scroll happens. With 0 the `List`
+    ///   scrolls at once.
+    ///
+    /// # Example
+    /// ```rust
+    /// list.scroll_to_index_animated(120, 8);
+    ///
+    /// while list.is_scrolling() {
+    ///     list.tick();
+    ///     renderer.draw(&mut list)?;
+    ///     ...
+    /// }
+    /// ```
+    pub fn scroll_to_index_animated(&mut self, i: usize, frames: usize) {
+        if frames == 0 {
+            self.scroll_to(i);
+            return;
         }
+
+        let target = i.min(self.scrollable_len().saturating_sub(1));
+
+        self.animation = (target != self.offset).then_some(ScrollAnimation {
+            from: self.offset,
+            target,
+            frame: 0,
+            frames,
+        });
+    }
+
+    /// Returns whether a scroll started by `scroll_to_index_animated` is still
+    /// in progress.
+    #[inline]
+    pub fn is_scrolling(&self) -> bool {
+        self.animation.is_some()
+    }
+
+    /// Advances the scroll started by `scroll_to_index_animated` by one frame.
+    /// Does nothing if the `List` is not scrolling.
+    pub fn tick(&mut self) {
+        let Some(animation) = &mut self.animation else {
+            return;
+        };
+
+        animation.frame += 1;
+
+        let ScrollAnimation { from, target, frame, frames } = *animation;
+        // Interpolated in signed space, as the scroll can go either way.
+        let offset = from as isize
+            + (target as isize - from as isize) * frame as isize / frames as isize;
+
+        if frame >= frames {
+            self.animation = None;
+        }
+
+        self.offset = (offset as usize).min(self.scrollable_len().saturating_sub(1));
+        self.changed();
+    }
+
+    /// Sets how many elements `scroll_up` and `scroll_down` move by. Scrolling
+    /// still stops at the first and last element.
+    ///
+    /// # Parameters
+    /// - `step`: The number of elements to scroll by. Defaults to 1, and a
+    ///   step of 0 is treated as 1.
+    ///
+    /// # Example
+    /// ```rust
+    /// // Scroll three elements at a time, like a mouse wheel notch.
+    /// list.set_scroll_step(3);
+    /// ```
+    #[inline]
+    pub fn set_scroll_step(&mut self, step: usize) {
+        self.scroll_step = step.max(1);
     }
 
     /// Finds the index of an element by its ID.
@@ -206,7 +396,7 @@ impl List {
     /// ```
     #[inline]
     pub fn find_id(&self, id: GeneratedId) -> Option<usize> {
-        self.elements.iter().position(|element| element.id() == id)
+        self.index.find(id, || self.elements.iter().position(|element| element.id() == id))
     }
 
     /// Returns the index of the first element that matches the given label.
@@ -286,6 +476,8 @@ impl List {
     /// ```
     pub fn at_mut(&mut self, i: usize) -> FtuiResult<&mut Text> {
         if i < self.elements.len() {
+            // The element may be edited through the reference.
+            self.changed();
             Ok(&mut self.elements[i])
         } else {
             Err(FtuiError::ListIndexOutOfBound)
@@ -316,15 +508,145 @@ impl List {
     pub fn remove(&mut self, i: usize) -> FtuiResult<()> {
         if i < self.elements.len() {
             self.elements.remove(i);
+            self.clamp_offset();
+            self.rebuild_index();
+            self.changed();
             Ok(())
         } else {
             Err(FtuiError::ListIndexOutOfBound)
         }
     }
 
+    fn rebuild_index(&mut self) {
+        self.index.rebuild(self.elements.iter().map(|element| element.id()));
+    }
+
     fn len(&self) -> usize {
         self.elements.len()
     }
+
+    /// Shows only the elements whose label contains `query`, ignoring case.
+    /// The elements are kept, and `find_id`, `find_label` and `at` still see
+    /// all of them. Pinned elements are always shown.
+    ///
+    /// # Parameters
+    /// - `query`: The text to look for.
+    ///
+    /// # Example
+    /// ```rust
+    /// // Only show the errors of a log.
+    /// log.set_filter("error");
+    /// ...
+    /// log.clear_filter();
+    /// ```
+    pub fn set_filter(&mut self, query: impl Into<String>) {
+        self.filter = Some(query.into().to_lowercase());
+        self.clamp_offset();
+        self.changed();
+    }
+
+    /// Removes the filter set with `set_filter`, showing every element again.
+    pub fn clear_filter(&mut self) {
+        self.filter = None;
+        self.clamp_offset();
+        self.changed();
+    }
+
+    /// Returns the number of elements shown with the current filter,
+    /// including the pinned elements.
+    ///
+    /// # Returns
+    /// `usize`: The number of elements that pass the filter.
+    ///
+    /// # Example
+    /// ```rust
+    /// list.set_filter("rs");
+    /// footer.set_label(format!("{} matches", list.visible_len()));
+    /// ```
+    #[inline]
+    pub fn visible_len(&self) -> usize {
+        self.pinned.min(self.len()) + self.scrollable_len()
+    }
+
+    /// Returns the indices of the elements after the pinned elements that
+    /// pass the filter.
+    fn scrollable(&self) -> impl Iterator<Item = usize> + '_ {
+        (self.pinned.min(self.len())..self.len()).filter(|&i| match &self.filter {
+            Some(query) => self.elements[i].label().to_lowercase().contains(query),
+            None => true,
+        })
+    }
+
+    /// Returns the number of elements that scroll, the ones after the pinned
+    /// elements that pass the filter.
+    #[inline]
+    fn scrollable_len(&self) -> usize {
+        match self.filter {
+            Some(_) => self.scrollable().count(),
+            None => self.len().saturating_sub(self.pinned),
+        }
+    }
+
+    #[inline]
+    fn clamp_offset(&mut self) {
+        self.offset = self.offset.min(self.scrollable_len().saturating_sub(1));
+    }
+
+    /// Returns how many elements fit in the given `Renderer` at once, taking
+    /// the header and footer rows into account.
+    ///
+    /// # Parameters
+    /// - `renderer`: The `Renderer` the `List` is drawn with.
+    ///
+    /// # Returns
+    /// `usize`: The number of visible elements.
+    ///
+    /// # Example
+    /// ```rust
+    /// // A 10 line renderer with a header and a footer shows 8 elements.
+    /// let list = ListBuilder::new()
+    ///     .header(...)?
+    ///     .footer(...)?
+    ///     .build();
+    ///
+    /// assert_eq!(list.capacity(&renderer), 8);
+    /// ```
+    pub fn capacity(&self, renderer: &Renderer) -> usize {
+        let (_, height) = renderer.get_dimensions();
+        let skip_top = if self.header.is_some() { 1 } else { 0 };
+        let skip_bottom = if self.footer.is_some() { 1 } else { 0 };
+
+        (height as usize).saturating_sub(skip_top + skip_bottom)
+    }
+}
+
+impl Scrollable for List {
+    #[inline]
+    fn offset(&self) -> usize {
+        self.offset
+    }
+
+    #[inline]
+    fn set_offset(&mut self, offset: usize) {
+        self.offset = offset;
+        self.animation = None;
+        self.changed();
+    }
+
+    #[inline]
+    fn content_len(&self) -> usize {
+        self.scrollable_len()
+    }
+
+    #[inline]
+    fn viewport(&self) -> usize {
+        self.viewport
+    }
+
+    #[inline]
+    fn scroll_step(&self) -> usize {
+        self.scroll_step
+    }
 }
 
 /// `ListBuilder` is used to create `List` instances using the builder pattern.
@@ -436,7 +758,45 @@ impl ListBuilder {
         Ok(self)
     }
 
+    /// Draws a scrollbar on the rightmost column, showing where the visible
+    /// rows are in the content. The column is taken from the List.
+    ///
+    /// # Returns
+    /// `ListBuilder`: Returns `self`.
+    ///
+    /// # Example
+    /// ```rust
+    /// ListBuilder::new()
+    ///     .scrollbar();
+    /// ```
+    pub fn scrollbar(mut self) -> Self {
+        self.list.scrollbar = Some(Scrollbar::default());
+        self
+    }
+
+    /// Same as `scrollbar`, with the given characters instead of `│` for the
+    /// track and `█` for the thumb.
+    ///
+    /// # Parameters
+    /// - `track`: The character drawn along the scrollbar.
+    /// - `thumb`: The character showing the visible rows.
+    ///
+    /// # Returns
+    /// `ListBuilder`: Returns `self`.
+    ///
+    /// # Example
+    /// ```rust
+    /// // For terminals without box drawing characters.
+    /// ListBuilder::new()
+    ///     .scrollbar_chars('|', '#');
+    /// ```
+    pub fn scrollbar_chars(mut self, track: char, thumb: char) -> Self {
+        self.list.scrollbar = Some(Scrollbar::new(track, thumb));
+        self
+    }
+
     /// Enables numbering for the `List`, adding a number prefix to each element.
+    /// Numbers are right-aligned so labels line up (` 9. Item`, `10. Item`).
     ///
     /// # Returns
     /// - `Self`: Returns `self`.
@@ -451,6 +811,75 @@ impl ListBuilder {
         self
     }
 
+    /// Sets the `TextFlags` used for the number prefix of a numbered `List`.
+    /// The prefix is styled with these flags only, instead of the flags of
+    /// its element, while the label keeps its own style. Only takes effect
+    /// together with `number`. Alignment flags are ignored.
+    ///
+    /// # Parameters
+    /// - `flags`: The `TextFlags` for the number prefix.
+    ///
+    /// # Returns
+    /// - `Ok(ListBuilder)`: Returns `self`.
+    /// - `Err(FtuiError)`: Returns an error.
+    ///
+    /// # Example
+    /// ```rust
+    /// // Dim numbers, normal labels.
+    /// ListBuilder::new()
+    ///     .number()
+    ///     .number_style_flags(TextFlags::STYLE_DIM)?;
+    /// ```
+    pub fn number_style_flags(mut self, flags: TextFlags) -> FtuiResult<Self> {
+        flags.ensure_compatibility()?;
+        self.list.number_flags = Some(flags);
+        Ok(self)
+    }
+
+    /// Pins the first `n` elements to the top of the `List`. Pinned elements
+    /// stay visible while the rest scrolls under them, which suits rows like
+    /// column headings. The scroll offset only counts the other elements.
+    ///
+    /// # Parameters
+    /// - `n`: The number of elements to pin. Defaults to 0.
+    ///
+    /// # Returns
+    /// - `Self`: Returns `self`.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut list = ListBuilder::new()
+    ///     .pinned_top(1)
+    ///     .build();
+    ///
+    /// list.add("Name      Size", None)?;
+    /// list.add_many(files, None)?;
+    ///
+    /// // "Name      Size" stays on the first row.
+    /// list.scroll_down();
+    /// ```
+    pub fn pinned_top(mut self, n: usize) -> Self {
+        self.list.pinned = n;
+        self
+    }
+
+    /// Keeps an index from element IDs to positions, making `find_id` an
+    /// `O(1)` lookup instead of a linear scan. Worth it for large `List`s
+    /// queried by ID often; `remove` becomes slower as the index is rebuilt.
+    ///
+    /// # Returns
+    /// - `Self`: Returns `self`.
+    ///
+    /// # Example
+    /// ```rust
+    /// ListBuilder::new()
+    ///     .indexed();
+    /// ```
+    pub fn indexed(mut self) -> Self {
+        self.list.index.enable(self.list.elements.iter().map(|element| element.id()));
+        self
+    }
+
     /// Renders the current `List` directly to the terminal without
     /// creating and returning a new one.
     ///
@@ -493,13 +922,14 @@ impl ListBuilder {
 
 impl RenderableMut<Renderer> for List {
     fn render(&mut self, renderer: &mut Renderer) -> FtuiResult<()> {
-        let (width, height) = renderer.get_dimensions();
+        let (width, _) = renderer.get_dimensions();
         let skip_top = if self.header.is_some() { 1 } else { 0 };  
-        let skip_bottom = if self.footer.is_some() { 1 } else { 0 };
-        let max_elements = (height - 1) as usize - skip_bottom;
-        let num_prefix = if self.is_numbered {
-            (num::digits(self.len() as u64) + 2) as usize 
-        } else { 0 };
+        let max_elements = self.capacity(renderer);
+        // Labels must not run into the scrollbar.
+        let bar_width = if self.scrollbar.is_some() { 1 } else { 0 };
+        let width = width.saturating_sub(bar_width as u16);
+        let num_width = num::digits(self.len() as u64) as usize;
+        let num_prefix = if self.is_numbered { num_width + 2 } else { 0 };
 
         renderer.clear();
 
@@ -508,31 +938,97 @@ impl RenderableMut<Renderer> for List {
         }
 
         if let Some(footer) = &mut self.footer {
-            renderer.render_text_as_footer(footer)?;
+            renderer.render_text_as_footer(footer, OverflowPolicy::Error)?;
         }
         
-        for (i, elt) in self
-            .elements
-            .iter_mut()
-            .skip(self.offset)
+        // Pinned elements come first, then the scrolled elements after them.
+        // Collected first as the elements are borrowed mutably below.
+        let pinned = self.pinned.min(self.len());
+        self.viewport = max_elements.saturating_sub(pinned);
+        let visible: Vec<usize> = (0..pinned)
+            .chain(self.scrollable().skip(self.offset))
             .take(max_elements)
-            .enumerate() 
-        {
-            renderer.ensure_label_inbound(elt.len())?;
-            elt.resolve_pos_custom_len(width, elt.len() + num_prefix);
+            .collect();
+
+        for (i, n) in visible.into_iter().enumerate() {
+            let elt = &mut self.elements[n];
+
+            renderer.ensure_label_inbound(elt.display_width() + num_prefix + bar_width)?;
+            elt.resolve_pos_custom_len(width, elt.display_width() + num_prefix);
 
             let line = renderer.line_mut(i + skip_top);
 
+            // Numbers are right-aligned within the gutter so every label
+            // starts at the same column.
             if self.is_numbered {
                 line.edit(
-                    &format!("{}. {}", i + 1 + self.offset, elt.label()), elt.pos());
+                    &format!(
+                        "{:>num_width$}. {}", n + 1, elt.label()),
+                    elt.pos());
             } else {
                 line.edit(elt.label(), elt.pos());
             }
 
             line.add_ansi_many(elt.styles());
+            elt.tick_flash();
+
+            // The number span starts with a reset so it does not inherit the
+            // element's style. The line style comes back after the span.
+            if let (true, Some(flags)) = (self.is_numbered, self.number_flags) {
+                let mut number_ansi = vec![ansi::ESC_COLOR_RESET.into()];
+                number_ansi.extend(flags.resolve_ansi());
+
+                line.add_span(elt.pos() as usize, num_width + 1, number_ansi);
+            }
+        }
+
+        if let Some(scrollbar) = &self.scrollbar {
+            scrollbar.render(
+                renderer, skip_top + pinned, self.viewport, self.offset, self.scrollable_len());
         }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_list_does_not_scroll() {
+        let mut list = ListBuilder::new().build();
+
+        assert!(!list.scroll_down());
+        assert!(!list.scroll_up());
+        assert!(Renderer::headless(20, 5).render(&mut list).is_ok());
+    }
+
+    #[test]
+    fn clear_resets_the_offset() {
+        let mut list = ListBuilder::new().build();
+        list.add_many(["a", "b", "c"], None).unwrap();
+
+        assert!(list.scroll_down());
+        list.clear();
+
+        assert!(!list.scroll_down());
+        assert!(!list.scroll_up());
+    }
+
+    #[test]
+    fn wide_elements_do_not_panic() {
+        let mut renderer = Renderer::headless(20, 3);
+        let mut list = ListBuilder::new().build();
+        list.add_many(["日本語のテキスト", "👨‍👩‍👧 family", "e\u{301}te\u{301}"], None).unwrap();
+
+        while list.scroll_down() {
+            renderer.render(&mut list).unwrap();
+        }
+        while list.scroll_up() {
+            renderer.render(&mut list).unwrap();
+        }
+
+        assert!(renderer.as_plain_string().starts_with("日本語のテキスト"));
+    }
+}
@@ -1,3 +1,5 @@
+use unicode_segmentation::UnicodeSegmentation;
+
 use crate::util::ansi;
 use crate::renderer::Renderer;
 use crate::error::FtuiResult;
@@ -28,10 +30,26 @@ impl MessageStyle {
             MessageStyle::Error => &MSG_ERRO_ANSI,
         }
     }
+
+    /// Returns the icon shown before messages of this style by default.
+    pub(crate) fn default_icon(self) -> &'static str {
+        match self {
+            MessageStyle::Info => "ℹ",
+            MessageStyle::Warning => "⚠",
+            MessageStyle::Error => "✖",
+        }
+    }
 }
 
 /// A specialized variant of `Container` used to display a centered message on a
 /// `Renderer`. The appearance of the message is defined by the `MessageStyle` enum.
+///
+/// The message may span multiple lines, either by containing `\n` or by being
+/// wider than the `Renderer`, in which case it is wrapped. The banner block sizes
+/// itself to the content, with one banner row above and below it.
+///
+/// With `with_icon`, an icon for the style is shown before the message, so the
+/// styles can be told apart without relying on color.
 /// 
 /// # Usage
 /// Use this to present informational messages, warnings, or errors to the user in
@@ -40,6 +58,8 @@ impl MessageStyle {
 pub struct Message {
     message: String,
     style: MessageStyle,
+    show_icon: bool,
+    icons: Option<[String; 3]>,
 }
 
 impl Message {
@@ -60,35 +80,119 @@ impl Message {
         Self {
             message: message.to_string(),
             style: style,
+            show_icon: false,
+            icons: None,
+        }
+    }
+
+    /// Shows an icon for the style before the message: `ℹ` for info, `⚠` for
+    /// warnings and `✖` for errors, unless replaced with `set_icons`.
+    ///
+    /// # Parameters
+    /// - `show`: Whether to show the icon. Defaults to `false`.
+    ///
+    /// # Returns
+    /// `Message`: Returns `self`.
+    ///
+    /// # Example
+    /// ```rust
+    /// // `⚠ Disk almost full`
+    /// let _ = Message::new("Disk almost full", MessageStyle::Warning)
+    ///     .with_icon(true);
+    /// ```
+    pub fn with_icon(mut self, show: bool) -> Self {
+        self.show_icon = show;
+        self
+    }
+
+    /// Replaces the icons shown with `with_icon`, one for each style.
+    ///
+    /// # Parameters
+    /// - `info`: The icon for `MessageStyle::Info`.
+    /// - `warning`: The icon for `MessageStyle::Warning`.
+    /// - `error`: The icon for `MessageStyle::Error`.
+    ///
+    /// # Example
+    /// ```rust
+    /// // Plain text icons for terminals without the glyphs.
+    /// message.set_icons("i", "!", "x");
+    /// ```
+    pub fn set_icons(
+        &mut self, info: impl ToString, warning: impl ToString, error: impl ToString
+    ) {
+        self.icons = Some([info.to_string(), warning.to_string(), error.to_string()]);
+    }
+
+    /// Returns the icon to show before the message, if any.
+    fn icon(&self) -> Option<&str> {
+        if !self.show_icon {
+            return None;
         }
+
+        Some(match &self.icons {
+            Some(icons) => &icons[self.style as usize],
+            None => self.style.default_icon(),
+        })
     }
 
-    pub(crate) fn len(&self) -> usize {
-        self.message.len()
+    /// Splits the message into the rows it occupies, breaking on `\n` and
+    /// wrapping any line wider than `width`.
+    pub(crate) fn rows(&self, width: usize) -> Vec<String> {
+        let mut rows = Vec::new();
+
+        for line in self.message.lines() {
+            let graphemes: Vec<&str> = line.graphemes(true).collect();
+
+            if graphemes.is_empty() {
+                rows.push(String::new());
+                continue;
+            }
+
+            for chunk in graphemes.chunks(width.max(1)) {
+                rows.push(chunk.concat());
+            }
+        }
+
+        rows
     }
 }
 
 impl RenderableMut<Renderer> for Message {
     fn render(&mut self, renderer: &mut Renderer) -> FtuiResult<()> {
-        renderer.ensure_label_inbound(self.len())?;
         let (width, height) = renderer.get_dimensions();
-        let message_line = (height as f32 / 2.0).round() as usize;
-        let x_pos = Renderer::calc_middle_align_pos(width, self.len());
-        let ansi = self.style.to_ansi();
+        let prefix = self.icon().map(|icon| format!("{} ", icon)).unwrap_or_default();
+        let prefix_len = prefix.graphemes(true).count();
 
-        let line = renderer.line_mut(message_line);
+        // The icon goes before the first row, which is centered with it.
+        let mut rows = self.rows((width as usize).saturating_sub(prefix_len));
+        if let Some(first) = rows.first_mut() {
+            first.insert_str(0, &prefix);
+        }
 
-        line.edit(&self.message, x_pos);
-        line.add_ansi_many(ansi);
+        let ansi = self.style.to_ansi();
 
-        renderer.lines_mut().get_mut(message_line - 1).map(|line| {
-            line.clear();
-            line.add_ansi_many(ansi);
-        });
-        renderer.lines_mut().get_mut(message_line + 1).map(|line| {
+        // The block is the content plus one banner row on each side, clamped
+        // to the renderer height. Banner rows are dropped before content rows.
+        let block = (rows.len() + 2).min(height as usize);
+        let banner_top = if block > rows.len() { 1 } else { 0 };
+        let banner_bottom = if block > rows.len() + 1 { 1 } else { 0 };
+        let top = (height as usize - block) / 2;
+
+        for line in &mut renderer.lines_mut()[top..(top + block)] {
             line.clear();
             line.add_ansi_many(ansi);
-        });
+        }
+
+        for (i, row) in rows
+            .iter()
+            .take(block - banner_top - banner_bottom)
+            .enumerate()
+        {
+            let len = row.graphemes(true).count();
+            let x_pos = Renderer::calc_middle_align_pos(width, len);
+
+            renderer.line_mut(top + banner_top + i).edit(row, x_pos);
+        }
 
         Ok(())
     }
@@ -13,3 +13,17 @@ pub use document::DocumentBuilder;
 mod message;
 pub use message::Message;
 pub use message::MessageStyle;
+
+mod toggle;
+pub use toggle::ToggleView;
+
+mod wrap;
+pub use wrap::WrapStrategy;
+pub use wrap::CharWrap;
+pub use wrap::WordWrap;
+pub use wrap::Truncate;
+pub use wrap::NoWrap;
+
+mod scrollable;
+pub use scrollable::Scrollable;
+pub use scrollable::ScrollKeys;
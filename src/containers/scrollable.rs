@@ -0,0 +1,211 @@
+use crossterm as ct;
+
+use crate::renderer::Renderer;
+use crate::util::ansi;
+
+/// A `trait` for containers that scroll through their content, such as `List`
+/// and `Document`. Implementors describe their content and offset, and get
+/// bounded scrolling from the provided methods, so scroll handling (keys,
+/// mouse wheel, ...) can be written once for any of them.
+///
+/// # Notes
+/// The offset is the first visible row of content. It is kept between the
+/// first and the last row, so the last row can be scrolled to the top.
+///
+/// # Example
+/// ```rust
+/// fn handle_scroll(view: &mut impl Scrollable, key: KeyCode) -> bool {
+///     match key {
+///         KeyCode::Up => view.scroll_up(),
+///         KeyCode::Down => view.scroll_down(),
+///         KeyCode::PageUp => view.page_up(),
+///         KeyCode::PageDown => view.page_down(),
+///         KeyCode::Home => view.scroll_to_top(),
+///         KeyCode::End => view.scroll_to_bottom(),
+///         _ => false,
+///     }
+/// }
+/// ```
+pub trait Scrollable {
+    /// Returns the first visible row of content.
+    fn offset(&self) -> usize;
+
+    /// Sets the first visible row of content. Called with an offset that is
+    /// already in bounds.
+    fn set_offset(&mut self, offset: usize);
+
+    /// Returns the number of rows of content that scroll.
+    fn content_len(&self) -> usize;
+
+    /// Returns how many rows of content were visible in the last render.
+    fn viewport(&self) -> usize;
+
+    /// Returns how many rows `scroll_up` and `scroll_down` move by.
+    fn scroll_step(&self) -> usize {
+        1
+    }
+
+    /// Scrolls to `offset`, clamped to the content.
+    ///
+    /// # Returns
+    /// - `true`: The offset changed.
+    /// - `false`: Already at that offset, or there is no content.
+    fn scroll_to(&mut self, offset: usize) -> bool {
+        if self.content_len() == 0 {
+            return false;
+        }
+
+        let offset = offset.min(self.content_len() - 1);
+
+        if offset != self.offset() {
+            self.set_offset(offset);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Scrolls up by the scroll step.
+    ///
+    /// # Returns
+    /// - `true`: The view scrolled up.
+    /// - `false`: Already at the top.
+    fn scroll_up(&mut self) -> bool {
+        self.scroll_to(self.offset().saturating_sub(self.scroll_step()))
+    }
+
+    /// Scrolls down by the scroll step.
+    ///
+    /// # Returns
+    /// - `true`: The view scrolled down.
+    /// - `false`: Already at the bottom, or there is no content.
+    fn scroll_down(&mut self) -> bool {
+        self.scroll_to(self.offset().saturating_add(self.scroll_step()))
+    }
+
+    /// Scrolls up by one viewport.
+    fn page_up(&mut self) -> bool {
+        self.scroll_to(self.offset().saturating_sub(self.viewport().max(1)))
+    }
+
+    /// Scrolls down by one viewport.
+    fn page_down(&mut self) -> bool {
+        self.scroll_to(self.offset().saturating_add(self.viewport().max(1)))
+    }
+
+    /// Scrolls to the first row.
+    fn scroll_to_top(&mut self) -> bool {
+        self.scroll_to(0)
+    }
+
+    /// Scrolls to the last row.
+    fn scroll_to_bottom(&mut self) -> bool {
+        self.scroll_to(usize::MAX)
+    }
+}
+
+/// The key bindings a scrollable container responds to in `handle_key`.
+///
+/// | Action           | `Vim`               | `Arrows`              |
+/// |------------------|---------------------|-----------------------|
+/// | Scroll up/down   | `k` / `j`           | `Up` / `Down`         |
+/// | Page up/down     |                     | `PageUp` / `PageDown` |
+/// | Half page        | `Ctrl+U` / `Ctrl+D` |                       |
+/// | Top/bottom       | `g` / `G`           | `Home` / `End`        |
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScrollKeys {
+    /// Only the vim style bindings.
+    Vim,
+    /// Only the arrow and navigation keys.
+    Arrows,
+    /// Both sets of bindings.
+    #[default]
+    Both,
+}
+
+impl ScrollKeys {
+    /// Applies `key` to `view` according to the bindings.
+    ///
+    /// # Returns
+    /// - `true`: The view scrolled.
+    /// - `false`: The key is not bound, or the view did not move.
+    pub(crate) fn apply(self, view: &mut impl Scrollable, key: ct::event::KeyEvent) -> bool {
+        use ct::event::KeyCode;
+
+        if key.kind == ct::event::KeyEventKind::Release {
+            return false;
+        }
+
+        let vim = self != ScrollKeys::Arrows;
+        let arrows = self != ScrollKeys::Vim;
+        let ctrl = key.modifiers.contains(ct::event::KeyModifiers::CONTROL);
+        let half_page = (view.viewport() / 2).max(1);
+
+        match key.code {
+            KeyCode::Char('u') if vim && ctrl =>
+                view.scroll_to(view.offset().saturating_sub(half_page)),
+            KeyCode::Char('d') if vim && ctrl =>
+                view.scroll_to(view.offset().saturating_add(half_page)),
+            _ if ctrl => false,
+            KeyCode::Char('k') if vim => view.scroll_up(),
+            KeyCode::Char('j') if vim => view.scroll_down(),
+            KeyCode::Char('g') if vim => view.scroll_to_top(),
+            KeyCode::Char('G') if vim => view.scroll_to_bottom(),
+            KeyCode::Up if arrows => view.scroll_up(),
+            KeyCode::Down if arrows => view.scroll_down(),
+            KeyCode::PageUp if arrows => view.page_up(),
+            KeyCode::PageDown if arrows => view.page_down(),
+            KeyCode::Home if arrows => view.scroll_to_top(),
+            KeyCode::End if arrows => view.scroll_to_bottom(),
+            _ => false,
+        }
+    }
+}
+
+/// A vertical scrollbar drawn on the rightmost column of a `Renderer`, next
+/// to the scrolled rows of a container.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Scrollbar {
+    track: char,
+    thumb: char,
+}
+
+impl Default for Scrollbar {
+    fn default() -> Self {
+        Self { track: '│', thumb: '█' }
+    }
+}
+
+impl Scrollbar {
+    pub(crate) fn new(track: char, thumb: char) -> Self {
+        Self { track, thumb }
+    }
+
+    /// Draws the scrollbar over the `rows` lines starting at `top`. The thumb
+    /// is sized to the share of the content that is visible, and placed
+    /// according to `offset`.
+    pub(crate) fn render(
+        &self, renderer: &mut Renderer, top: usize, rows: usize, offset: usize, content_len: usize
+    ) {
+        let (width, _) = renderer.get_dimensions();
+
+        if width == 0 || rows == 0 {
+            return;
+        }
+
+        let thumb_len = if content_len <= rows { rows } else { (rows * rows / content_len).max(1) };
+        // The offset goes up to the last row of content.
+        let max_offset = content_len.saturating_sub(1).max(1);
+        let thumb_top = offset.min(max_offset) * (rows - thumb_len) / max_offset;
+        let column = width as usize - 1;
+
+        for i in 0..rows {
+            let c = if (thumb_top..thumb_top + thumb_len).contains(&i) { self.thumb } else { self.track };
+            let line = renderer.line_mut(top + i);
+
+            line.edit_iter(std::iter::once(c), column as u16);
+            // Keep the style of the row off the scrollbar.
+            line.add_span(column, 1, vec![ansi::ESC_COLOR_RESET.into()]);
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use crate::renderer::Renderer;
+use crate::error::FtuiResult;
+use crate::util::RenderableMut;
+
+/// Holds two views and renders one of them at a time, for apps that flip
+/// between two screens with a key, such as a list and the details of an item.
+/// Both views keep their state (scroll position, selection, ...) while the
+/// other one is shown.
+///
+/// # Usage
+/// Render it like any container; it renders the active view.
+///
+/// # Example
+/// ```rust
+/// let mut view = ToggleView::new(list, details);
+///
+/// loop {
+///     renderer.draw(&mut view)?;
+///
+///     match input::key()? {
+///         Some(KeyCode::Tab) => view.toggle(),
+///         Some(KeyCode::Down) if view.is_first_active() => view.first_mut().scroll_down(),
+///         ...
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToggleView<A, B> {
+    first: A,
+    second: B,
+    second_active: bool,
+}
+
+impl<A, B> ToggleView<A, B> {
+    /// Creates a new `ToggleView` showing `first`.
+    ///
+    /// # Parameters
+    /// - `first`: The view shown at first.
+    /// - `second`: The view shown after a `toggle`.
+    ///
+    /// # Returns
+    /// A new `ToggleView` instance.
+    ///
+    /// # Example
+    /// ```rust
+    /// let _ = ToggleView::new(list, document);
+    /// ```
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second, second_active: false }
+    }
+
+    /// Switches to the view that is not shown.
+    #[inline]
+    pub fn toggle(&mut self) {
+        self.second_active = !self.second_active;
+    }
+
+    /// Returns `true` if the first view is shown, `false` if the second one is.
+    #[inline]
+    pub fn is_first_active(&self) -> bool {
+        !self.second_active
+    }
+
+    #[inline]
+    pub fn first(&self) -> &A {
+        &self.first
+    }
+
+    #[inline]
+    pub fn first_mut(&mut self) -> &mut A {
+        &mut self.first
+    }
+
+    #[inline]
+    pub fn second(&self) -> &B {
+        &self.second
+    }
+
+    #[inline]
+    pub fn second_mut(&mut self) -> &mut B {
+        &mut self.second
+    }
+}
+
+impl<A, B> RenderableMut<Renderer> for ToggleView<A, B>
+where
+    A: RenderableMut<Renderer>,
+    B: RenderableMut<Renderer>,
+{
+    fn render(&mut self, renderer: &mut Renderer) -> FtuiResult<()> {
+        if self.second_active {
+            self.second.render(renderer)
+        } else {
+            self.first.render(renderer)
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use std::sync::Arc;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::util;
+
+/// A `trait` deciding how a `Document` breaks a line of its content into the
+/// rows that are rendered.
+///
+/// # Notes
+/// `wrap` is called once per line of the content, without the line ending.
+/// Rows wider than `width` are clipped when rendered, and a line wrapped into
+/// no rows is hidden.
+///
+/// # Example
+/// ```rust
+/// // Show every line in upper case, cut at the width.
+/// struct Shout;
+///
+/// impl WrapStrategy for Shout {
+///     fn wrap(&self, line: &str, width: usize) -> Vec<String> {
+///         vec![line.to_uppercase().chars().take(width).collect()]
+///     }
+/// }
+///
+/// DocumentBuilder::new()
+///     .wrap_strategy(Box::new(Shout));
+/// ```
+///
+/// # Notes
+/// Strategies must be `Send` and `Sync`, so a `Document` can be moved to
+/// other threads.
+pub trait WrapStrategy: Send + Sync {
+    /// Breaks `line` into rows at most `width` characters wide.
+    ///
+    /// The built-in strategies count characters like the `Renderer`, which
+    /// gives each one a cell, so their rows are never clipped. A character
+    /// and the marks combined with it are never split across rows.
+    fn wrap(&self, line: &str, width: usize) -> Vec<String>;
+}
+
+/// Returns how many cells of a `Renderer` line `grapheme` takes.
+#[inline]
+fn cells(grapheme: &str) -> usize {
+    grapheme.chars().count()
+}
+
+/// Breaks lines at exactly `width` characters, even in the middle of a word.
+/// This is the default for a `Document`.
+///
+/// `The quick brown fo`
+/// `x jumps`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CharWrap;
+
+impl WrapStrategy for CharWrap {
+    fn wrap(&self, line: &str, width: usize) -> Vec<String> {
+        let width = width.max(1);
+        let mut rows = vec![];
+        let mut row = String::new();
+        let mut row_len = 0;
+
+        for grapheme in line.graphemes(true) {
+            let len = cells(grapheme);
+
+            if row_len != 0 && row_len + len > width {
+                rows.push(std::mem::take(&mut row));
+                row_len = 0;
+            }
+
+            row.push_str(grapheme);
+            row_len += len;
+        }
+
+        rows.push(row);
+        rows
+    }
+}
+
+/// Breaks lines between words. Words wider than `width` are cut like
+/// `CharWrap`.
+///
+/// `The quick brown`
+/// `fox jumps`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WordWrap;
+
+impl WrapStrategy for WordWrap {
+    fn wrap(&self, line: &str, width: usize) -> Vec<String> {
+        let width = width.max(1);
+        let mut rows = vec![];
+        let mut row = String::new();
+        let mut row_len = 0;
+
+        for word in line.split_inclusive(' ') {
+            let word_len = word.trim_end_matches(' ').chars().count();
+
+            if row_len != 0 && row_len + word_len > width {
+                rows.push(row.trim_end().to_string());
+                row.clear();
+                row_len = 0;
+            }
+
+            for grapheme in word.graphemes(true) {
+                let len = cells(grapheme);
+
+                if row_len != 0 && row_len + len > width {
+                    // Spaces at the end of a full row are dropped.
+                    if grapheme == " " {
+                        continue;
+                    }
+
+                    rows.push(std::mem::take(&mut row));
+                    row_len = 0;
+                }
+
+                row.push_str(grapheme);
+                row_len += len;
+            }
+        }
+
+        rows.push(row);
+        rows
+    }
+}
+
+/// Keeps every line on one row, cutting it to `width` and ending it with `…`.
+///
+/// `The quick brown f…`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Truncate;
+
+impl WrapStrategy for Truncate {
+    fn wrap(&self, line: &str, width: usize) -> Vec<String> {
+        vec![util::truncate(line, width)]
+    }
+}
+
+/// Keeps every line on one row, clipping it at the edge of the `Renderer`.
+///
+/// `The quick brown fo`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NoWrap;
+
+impl WrapStrategy for NoWrap {
+    fn wrap(&self, line: &str, _: usize) -> Vec<String> {
+        vec![line.to_string()]
+    }
+}
+
+/// The `WrapStrategy` of a `Document`. The built-in strategies set with
+/// `word_wrap` compare by kind, so `Document`s built the same way are equal.
+/// Custom strategies are shared by clones, and only equal if shared.
+#[derive(Clone, Default)]
+pub(crate) enum Wrapper {
+    #[default]
+    Char,
+    Word,
+    Custom(Arc<dyn WrapStrategy>),
+}
+
+impl Wrapper {
+    pub(crate) fn new(strategy: Box<dyn WrapStrategy>) -> Self {
+        Wrapper::Custom(Arc::from(strategy))
+    }
+
+    #[inline]
+    pub(crate) fn wrap(&self, line: &str, width: usize) -> Vec<String> {
+        match self {
+            Wrapper::Char => CharWrap.wrap(line, width),
+            Wrapper::Word => WordWrap.wrap(line, width),
+            Wrapper::Custom(strategy) => strategy.wrap(line, width),
+        }
+    }
+}
+
+impl std::fmt::Debug for Wrapper {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Wrapper::Char => f.write_str("Wrapper::Char"),
+            Wrapper::Word => f.write_str("Wrapper::Word"),
+            Wrapper::Custom(_) => f.write_str("Wrapper::Custom(..)"),
+        }
+    }
+}
+
+impl PartialEq for Wrapper {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Wrapper::Char, Wrapper::Char) | (Wrapper::Word, Wrapper::Word) => true,
+            (Wrapper::Custom(a), Wrapper::Custom(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Wrapper {}
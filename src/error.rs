@@ -1,5 +1,6 @@
 use thiserror::Error;
 use std::io;
+use std::path::PathBuf;
 
 /// An `enum` representing all possible errors that can occur in `Feather-TUI`.
 ///
@@ -43,6 +44,39 @@ pub enum FtuiError {
     #[error("TextFlags cannot contain multiple color.")]
     TextFlagMultipleColor,
 
+    /// Occurs when `TextFlags::tailwind` is given a token it does not know.
+    /// Carries the unknown token.
+    ///
+    /// # Example
+    /// ```rust
+    /// fn main() -> FtuiResult<()> {
+    ///     // `bogus` is not a valid token.
+    ///     TextFlags::tailwind("c-r bogus")?;
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    #[error("Unknown tailwind style token `{0}`.")]
+    TextFlagUnknownTailwindToken(String),
+
+    /// Occurs when the label of a `Text` contains a control character while
+    /// the container rejects them with `ControlChars::Error`. Carries the
+    /// offending character.
+    ///
+    /// # Example
+    /// ```rust
+    /// fn main() -> FtuiResult<()> {
+    ///     // The `\r` results in an error.
+    ///     ContainerBuilder::new()
+    ///         .control_chars(ControlChars::Error)
+    ///         .text("Done\r", None)?;
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    #[error("Text label contains the control character {0:?}.")]
+    TextControlChar(char),
+
     /// Occurs when attempting to query a component by its ID, but no such
     /// component exists in the container.
     ///
@@ -100,6 +134,35 @@ pub enum FtuiError {
     #[error("")]
     DimensionsTerminalToSmall,
 
+    /// Occurs when `DocumentBuilder::from_file` reads a file that is not valid
+    /// UTF-8. Use `DocumentBuilder::from_file_lossy` to view such files anyway.
+    ///
+    /// # Example
+    /// ```rust
+    /// fn main() -> FtuiResult<()> {
+    ///     // This results in an error if "image.png" is not valid UTF-8.
+    ///     DocumentBuilder::new().from_file("image.png")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[error("File {path:?} is not valid UTF-8.")]
+    DocumentInvalidUtf8 { path: PathBuf },
+
+    /// Occurs when a `Calendar` is created with a date that does not exist.
+    ///
+    /// # Example
+    /// ```rust
+    /// fn main() -> FtuiResult<()> {
+    ///     // There is no February 30, this results in the error.
+    ///     Calendar::new(2026, 2, 30)?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[error("The date does not exist.")]
+    CalendarInvalidDate,
+
     /// Occurs when attempting to call the `Renderer::render` method with a container
     /// that exceeds the dimensions of the renderer. There are two cases where a
     /// container is considered "too big":
@@ -151,6 +214,41 @@ pub enum FtuiError {
     /// ```
     #[error("Std Input Output Error: {0}")]
     StdInputOutputError(#[from] io::Error),
+
+    /// Occurs when an `EventSource` is waited on without a timeout but has no
+    /// events left, for example a `MockEventSource` whose scripted events
+    /// have all been read.
+    ///
+    /// # Example
+    /// ```rust
+    /// fn main() -> FtuiResult<()> {
+    ///     let mut source = MockEventSource::new(vec![]);
+    ///
+    ///     // There is nothing to read, so this results in the error.
+    ///     prompt_inline_from(&mut source, &mut renderer, 0, "Name")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[error("The event source has no events left.")]
+    InputEventSourceExhausted,
+
+    /// Occurs when the system clipboard cannot be opened or written to. This
+    /// enum wraps an error from `arboard::Error` and is only available with
+    /// the `clipboard` feature.
+    ///
+    /// # Example
+    /// ```rust
+    /// fn main() -> FtuiResult<()> {
+    ///     // This function may return an error if the clipboard is unavailable.
+    ///     renderer.copy_to_clipboard()?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "clipboard")]
+    #[error("Clipboard Error: {0}")]
+    ClipboardError(#[from] arboard::Error),
 }
 
 /// Implementation of the `PartialEq` trait for the `FtuiError` enum. This is necessary
@@ -184,10 +282,17 @@ impl PartialEq for FtuiError {
         match (self, other) {
             (TextFlagNoneWithOther, TextFlagNoneWithOther) => true,
             (TextFlagMultipleColor, TextFlagMultipleColor) => true,
+            (TextFlagUnknownTailwindToken(a), TextFlagUnknownTailwindToken(b)) => a == b,
+            (TextControlChar(a), TextControlChar(b)) => a == b,
             (ContainerNoComponentById, ContainerNoComponentById) => true,
             (ListIndexOutOfBound, ListIndexOutOfBound) => true,
+            (DocumentInvalidUtf8 { path: a }, DocumentInvalidUtf8 { path: b }) => a == b,
+            (CalendarInvalidDate, CalendarInvalidDate) => true,
             (RendererContainerTooBig, RendererContainerTooBig) => true,
             (StdInputOutputError(_), StdInputOutputError(_)) => true,
+            (InputEventSourceExhausted, InputEventSourceExhausted) => true,
+            #[cfg(feature = "clipboard")]
+            (ClipboardError(_), ClipboardError(_)) => true,
             _ => false,
         }
     }
@@ -1,9 +1,118 @@
+use std::collections::VecDeque;
 use std::io;
 use std::io::Write;
+use std::time::Duration;
 
 use crossterm as ct;
 
+use crate::error::FtuiError;
 use crate::error::FtuiResult;
+use crate::renderer::Renderer;
+
+/// How long `key` and `key_event` wait for a key press.
+const KEY_POLL_TIMEOUT: Duration = Duration::from_millis(16);
+
+/// A source of terminal events for the functions in the `input` module.
+/// The real terminal is `TerminalEventSource`; `MockEventSource` replays a
+/// scripted list of events, which makes whole UI flows testable without a
+/// terminal.
+pub trait EventSource {
+    /// Waits for the next event.
+    ///
+    /// # Parameters
+    /// - `timeout`: How long to wait, or `None` to wait until an event arrives.
+    ///
+    /// # Returns
+    /// - `Ok(Some(Event))`: The next event.
+    /// - `Ok(None)`: No event arrived before the timeout.
+    /// - `Err(FtuiError)`: Returns an error.
+    fn next_event(
+        &mut self, timeout: Option<Duration>
+    ) -> FtuiResult<Option<ct::event::Event>>;
+}
+
+/// Reads events from the real terminal. Raw mode is enabled while waiting for
+/// an event and restored afterward.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TerminalEventSource;
+
+impl EventSource for TerminalEventSource {
+    fn next_event(
+        &mut self, timeout: Option<Duration>
+    ) -> FtuiResult<Option<ct::event::Event>> {
+        let was_raw = ct::terminal::is_raw_mode_enabled()?;
+        ct::terminal::enable_raw_mode()?;
+
+        let event = match timeout {
+            Some(timeout) if !ct::event::poll(timeout)? => Ok(None),
+            _ => ct::event::read().map(Some),
+        };
+
+        if !was_raw {
+            ct::terminal::disable_raw_mode()?;
+        }
+
+        Ok(event?)
+    }
+}
+
+/// Replays a preset list of events in order, for tests and recorded sessions.
+///
+/// # Notes
+/// - Once every event is read, waiting with a timeout returns `Ok(None)` and
+///   waiting without one fails with `FtuiError::InputEventSourceExhausted`.
+///
+/// # Example
+/// ```rust
+/// use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+///
+/// let mut source = MockEventSource::new(vec![
+///     Event::Key(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE)),
+///     Event::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)),
+/// ]);
+///
+/// while let Some(code) = key_from(&mut source)? {
+///     match code {
+///         KeyCode::Down => container.options_mut().selector_down(),
+///         KeyCode::Enter => container.options_mut().selector_select(),
+///         _ => false,
+///     };
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct MockEventSource {
+    events: VecDeque<ct::event::Event>,
+}
+
+impl MockEventSource {
+    pub fn new(events: impl IntoIterator<Item = ct::event::Event>) -> Self {
+        Self {
+            events: events.into_iter().collect(),
+        }
+    }
+
+    /// Adds an event after the ones not yet read.
+    pub fn push(&mut self, event: ct::event::Event) {
+        self.events.push_back(event);
+    }
+
+    /// Returns the number of events not yet read.
+    pub fn remaining(&self) -> usize {
+        self.events.len()
+    }
+}
+
+impl EventSource for MockEventSource {
+    fn next_event(
+        &mut self, timeout: Option<Duration>
+    ) -> FtuiResult<Option<ct::event::Event>> {
+        match (self.events.pop_front(), timeout) {
+            (Some(event), _) => Ok(Some(event)),
+            (None, Some(_)) => Ok(None),
+            (None, None) => Err(FtuiError::InputEventSourceExhausted),
+        }
+    }
+}
 
 /// Reads a line of input from the user after displaying a prompt.
 ///
@@ -44,6 +153,92 @@ pub fn prompt(promt: &str) -> FtuiResult<String> {
     Ok(line)
 }
 
+/// Reads a line of input on a single row of an already rendered `Renderer`
+/// buffer, leaving the rest of the UI on screen. The prompt and the typed text
+/// are echoed into `row` as the user types. Backspace deletes a character and
+/// Enter finishes the input. Pasted text is inserted as a whole, with its line
+/// breaks removed, when bracketed paste is enabled (see `ready`).
+///
+/// # Parameters
+/// - `renderer`: The `Renderer` holding the current frame.
+/// - `row`: The line of the buffer to read the input on.
+/// - `prompt`: The message displayed before the user input.
+///
+/// # Returns
+/// - `Ok(String)`: The user’s input, without the newline.
+/// - `Err(FtuiError)`: Returns an error.
+///
+/// # Notes
+/// - The row is overwritten, render the container again afterward to restore it.
+///
+/// # Example
+/// ```rust
+/// // Draw the UI, then read a search query on the bottom row.
+/// renderer.draw(&mut container)?;
+/// let query = prompt_inline(&mut renderer, height - 1, "Search")?;
+/// ```
+pub fn prompt_inline(
+    renderer: &mut Renderer, row: u16, prompt: &str
+) -> FtuiResult<String> {
+    // Stay in raw mode for the whole input so nothing typed between two
+    // key presses is echoed by the terminal.
+    let was_raw = ct::terminal::is_raw_mode_enabled()?;
+    ct::terminal::enable_raw_mode()?;
+
+    let input = prompt_inline_from(&mut TerminalEventSource, renderer, row, prompt);
+
+    if !was_raw {
+        ct::terminal::disable_raw_mode()?;
+    }
+
+    input
+}
+
+/// Same as `prompt_inline`, but reads the key presses from `source`.
+pub fn prompt_inline_from(
+    source: &mut impl EventSource, renderer: &mut Renderer, row: u16, prompt: &str
+) -> FtuiResult<String> {
+    let mut input = String::new();
+
+    loop {
+        if let Some(line) = renderer.lines_mut().get_mut(row as usize) {
+            line.clear();
+        }
+        renderer.put_str(0, row, &format!("{} -> {}", prompt, input), None)?;
+        // Only the prompt row changes, so leave the rest of the screen and
+        // the frame history alone.
+        renderer.flush_line_to(&mut io::stdout().lock(), row)?;
+
+        match source.next_event(None)? {
+            Some(ct::event::Event::Key(event)) => {
+                if event.kind == ct::event::KeyEventKind::Release {
+                    continue;
+                }
+
+                let modified = event.modifiers
+                    .intersects(ct::event::KeyModifiers::CONTROL | ct::event::KeyModifiers::ALT);
+
+                match event.code {
+                    ct::event::KeyCode::Enter => break,
+                    ct::event::KeyCode::Backspace => { input.pop(); },
+                    // Key combinations such as `Ctrl+A` do not type.
+                    ct::event::KeyCode::Char(_) if modified => {},
+                    ct::event::KeyCode::Char(c) => input.push(c),
+                    _ => {},
+                }
+            }
+            // The input is a single line, so line breaks in the paste must
+            // not end it.
+            Some(ct::event::Event::Paste(text)) => {
+                input.extend(text.chars().filter(|c| *c != '\n' && *c != '\r'));
+            }
+            _ => {},
+        }
+    }
+
+    Ok(input)
+}
+
 /// Reads a key press event as `KeyCode` from the terminal without blocking.
 ///
 /// # Returns
@@ -52,7 +247,8 @@ pub fn prompt(promt: &str) -> FtuiResult<String> {
 /// - `Err(FtuiError)`: Returns an error. 
 ///
 /// # Notes
-/// - This function does not block waiting for input.
+/// - This function does not block waiting for input. It waits up to 16
+///   milliseconds for a key press; use `key_timeout` to choose how long.
 ///
 /// # Example
 /// ```rust
@@ -67,21 +263,91 @@ pub fn prompt(promt: &str) -> FtuiResult<String> {
 /// }
 /// ```
 pub fn key() -> FtuiResult<Option<ct::event::KeyCode>> {
-    let mut key_code: Option<ct::event::KeyCode> = None;
-    ct::terminal::enable_raw_mode()?;
+    key_from(&mut TerminalEventSource)
+} 
 
-    if ct::event::poll(std::time::Duration::from_millis(16))? {
-        match ct::event::read()? {
-            ct::event::Event::Key(event) => {
-                key_code = Some(event.code);
-            }
-            _ => {}
-        }
+/// Same as `key`, but reads the key press from `source`.
+pub fn key_from(source: &mut impl EventSource) -> FtuiResult<Option<ct::event::KeyCode>> {
+    key_timeout_from(source, KEY_POLL_TIMEOUT)
+}
+
+/// Same as `key`, but waits up to `timeout` for a key press instead of 16
+/// milliseconds. A longer timeout uses less CPU in a background tool, one
+/// near zero keeps a fast game loop responsive.
+///
+/// # Parameters
+/// - `timeout`: How long to wait for a key press.
+///
+/// # Returns
+/// - `Ok(Some(KeyCode))`: If a key event is detected.
+/// - `Ok(None)`: If no key event is detected before the timeout.
+/// - `Err(FtuiError)`: Returns an error.
+///
+/// # Notes
+/// - Raw mode is enabled and restored on every call, which costs a few
+///   system calls each time. In a tight loop with a short timeout, enable raw
+///   mode once beforehand (for example with `terminal::ready`) so the calls
+///   leave it as is.
+///
+/// # Example
+/// ```rust
+/// // Check for input about ten times per second.
+/// loop {
+///     if let Some(code) = key_timeout(Duration::from_millis(100))? {
+///         ...
+///     }
+///     refresh_stats();
+/// }
+/// ```
+pub fn key_timeout(timeout: Duration) -> FtuiResult<Option<ct::event::KeyCode>> {
+    key_timeout_from(&mut TerminalEventSource, timeout)
+}
+
+/// Same as `key_timeout`, but reads the key press from `source`.
+pub fn key_timeout_from(
+    source: &mut impl EventSource, timeout: Duration
+) -> FtuiResult<Option<ct::event::KeyCode>> {
+    match source.next_event(Some(timeout))? {
+        Some(ct::event::Event::Key(event)) => Ok(Some(event.code)),
+        _ => Ok(None),
     }
+}
 
-    ct::terminal::disable_raw_mode()?;
-    Ok(key_code)
-} 
+/// Reads a key press event from the terminal without blocking, like `key`,
+/// but returns the whole `KeyEvent`, modifiers included, to tell `Ctrl+C` or
+/// `Shift+Tab` apart from plain keys.
+///
+/// # Returns
+/// - `Ok(Some(KeyEvent))`: If a key event is detected.
+/// - `Ok(None)`: If no key event is detected.
+/// - `Err(FtuiError)`: Returns an error.
+///
+/// # Example
+/// ```rust
+/// fn main() -> FtuiResult<()> {
+///     match key_event()? {
+///         Some(event) if event.modifiers.contains(KeyModifiers::CONTROL)
+///             && event.code == KeyCode::Char('c') => return Ok(()),
+///         Some(event) if event.code == KeyCode::BackTab => focus_previous(),
+///         _ => {},
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub fn key_event() -> FtuiResult<Option<ct::event::KeyEvent>> {
+    key_event_from(&mut TerminalEventSource)
+}
+
+/// Same as `key_event`, but reads the key press from `source`.
+pub fn key_event_from(
+    source: &mut impl EventSource
+) -> FtuiResult<Option<ct::event::KeyEvent>> {
+    match source.next_event(Some(KEY_POLL_TIMEOUT))? {
+        Some(ct::event::Event::Key(event)) => Ok(Some(event)),
+        _ => Ok(None),
+    }
+}
 
 /// Converts a `KeyCode` into its corresponding character, if applicable.
 ///
@@ -145,12 +411,104 @@ pub fn key_char() -> FtuiResult<Option<char>> {
     }
 } 
 
-pub fn wait_for_keypress() -> FtuiResult<()> {
+/// Blocks until a key is pressed and returns it, for "press any key to
+/// continue" prompts that then branch on the key.
+///
+/// # Returns
+/// - `Ok(KeyCode)`: The key that was pressed.
+/// - `Err(FtuiError)`: Returns an error.
+///
+/// # Notes
+/// - Raw mode is enabled while waiting and restored afterward, so the key is
+///   not echoed and does not need Enter.
+///
+/// # Example
+/// ```rust
+/// println!("Save changes? [y/n]");
+///
+/// if wait_for_key()? == KeyCode::Char('y') {
+///     save()?;
+/// }
+/// ```
+pub fn wait_for_key() -> FtuiResult<ct::event::KeyCode> {
+    // Stay in raw mode for the whole wait so key releases and anything
+    // typed meanwhile are not echoed by the terminal.
+    let was_raw = ct::terminal::is_raw_mode_enabled()?;
+    ct::terminal::enable_raw_mode()?;
+
+    let key = wait_for_key_from(&mut TerminalEventSource);
+
+    if !was_raw {
+        ct::terminal::disable_raw_mode()?;
+    }
+
+    key
+}
+
+/// Same as `wait_for_key`, but reads the key press from `source`.
+pub fn wait_for_key_from(source: &mut impl EventSource) -> FtuiResult<ct::event::KeyCode> {
     loop {
-        if let Some(_) = key()? {
-            break;
+        if let Some(ct::event::Event::Key(event)) = source.next_event(None)?
+            && event.kind != ct::event::KeyEventKind::Release
+        {
+            return Ok(event.code);
         }
-        std::thread::sleep(std::time::Duration::from_millis(1));
     }
-    Ok(())
+}
+
+/// Blocks until a key is pressed.
+#[deprecated(note = "use `wait_for_key`, which returns the pressed key")]
+pub fn wait_for_keypress() -> FtuiResult<()> {
+    wait_for_key().map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::containers::GeneralBuilder;
+
+    fn press(code: ct::event::KeyCode) -> ct::event::Event {
+        ct::event::Event::Key(ct::event::KeyEvent::from(code))
+    }
+
+    #[test]
+    fn menu_driven_by_mock_events() {
+        let mut quit = 0;
+        let mut container = GeneralBuilder::new()
+            .option("New")
+            .option_disabled("Locked")
+            .option_id("Quit", &mut quit)
+            .build();
+        let mut renderer = Renderer::headless(20, 5);
+        let mut source = MockEventSource::new([
+            press(ct::event::KeyCode::Down),
+            press(ct::event::KeyCode::Down),
+            press(ct::event::KeyCode::Enter),
+        ]);
+
+        renderer.render(&mut container).unwrap();
+
+        while let Some(code) = key_from(&mut source).unwrap() {
+            match code {
+                ct::event::KeyCode::Up => container.options_mut().selector_up(),
+                ct::event::KeyCode::Down => container.options_mut().selector_down(),
+                ct::event::KeyCode::Enter => container.options_mut().selector_select(),
+                _ => false,
+            };
+            renderer.render(&mut container).unwrap();
+        }
+
+        assert_eq!(source.remaining(), 0);
+        assert_eq!(container.selected_option().map(|option| option.label().as_str()), Some("Quit"));
+        assert!(container.options_mut().query_mut(quit).unwrap().is_selc());
+    }
+
+    #[test]
+    fn exhausted_source_fails_without_timeout() {
+        let mut source = MockEventSource::new([press(ct::event::KeyCode::Esc)]);
+
+        assert_eq!(wait_for_key_from(&mut source), Ok(ct::event::KeyCode::Esc));
+        assert_eq!(key_from(&mut source), Ok(None));
+        assert_eq!(wait_for_key_from(&mut source), Err(FtuiError::InputEventSourceExhausted));
+    }
 }
@@ -1,18 +1,42 @@
+use std::io;
 use std::io::Write;
+use std::time::Duration;
+use std::time::Instant;
+
+use crossterm as ct;
+use unicode_segmentation::UnicodeSegmentation;
 
 use crate::components as cpn;
 use crate::error::FtuiError;
 use crate::error::FtuiResult;
+use crate::input;
+use crate::input::EventSource;
+use crate::input::TerminalEventSource;
+use crate::util;
 use crate::util::ansi;
+use crate::util::ansi::Ansi;
 use crate::util::Dimension;
+use crate::util::OverflowPolicy;
+use crate::util::Rectangle;
 use crate::util::RenderableMut;
 
 const WHITESPACE_CHAR: char = ' ';
+const REPLACEMENT_CHAR: char = '\u{FFFD}';
+
+/// A run of characters in a `Line` styled separately from the rest of it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Span {
+    begin: usize,
+    end: usize,
+    ansi: Vec<Ansi>,
+    link: Option<String>,
+}
 
 /// A helper class for `Renderer`.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct Line {
-    ansi: Vec<&'static str>,
+    ansi: Vec<Ansi>,
+    spans: Vec<Span>,
     width: usize,
     data: Vec<char>,
 }
@@ -23,20 +47,50 @@ impl Line {
 
         Line {
             ansi: Vec::new(),
+            spans: Vec::new(),
             width: width,
             data: std::iter::repeat(WHITESPACE_CHAR).take(width).collect(),
         }
     }
 
     #[inline]
-    pub fn add_ansi(&mut self, value: &'static str) {
-        self.ansi.push(value);
+    pub fn add_ansi(&mut self, value: impl Into<Ansi>) {
+        self.ansi.push(value.into());
     }
 
     #[inline]
-    pub fn add_ansi_many(&mut self, value: &[&'static str]) {
+    pub fn add_ansi_many<S: Clone + Into<Ansi>>(&mut self, value: &[S]) {
         self.ansi.reserve(value.len());
-        self.ansi.extend(value.iter().copied());
+        self.ansi.extend(value.iter().cloned().map(Into::into));
+    }
+
+    /// Styles `len` characters starting at `begin` with `ansi`, on top of
+    /// the ANSI of the whole line. The line's own ANSI is restored after the
+    /// span.
+    pub fn add_span(&mut self, begin: usize, len: usize, ansi: Vec<Ansi>) {
+        let end = (begin + len).min(self.width);
+
+        if begin < end && !ansi.is_empty() {
+            self.spans.push(Span { begin, end, ansi, link: None });
+        }
+    }
+
+    /// Makes `len` characters starting at `begin` a hyperlink to `url`, using
+    /// an OSC 8 escape sequence. The escapes take no columns, and terminals
+    /// without support simply show the text.
+    pub fn add_link(&mut self, begin: usize, len: usize, url: String) {
+        let end = (begin + len).min(self.width);
+
+        if begin < end {
+            self.spans.push(Span { begin, end, ansi: vec![], link: Some(url) });
+        }
+    }
+
+    /// Returns whether the line has any ANSI, either for the whole line or
+    /// for a span of it.
+    #[inline]
+    pub fn has_ansi(&self) -> bool {
+        !self.ansi.is_empty() || !self.spans.is_empty()
     }
 
     #[inline]
@@ -56,22 +110,21 @@ impl Line {
         }
     }
 
+    /// Writes `data` starting at column `begin`, one character per cell.
+    /// Anything past the end of the line is clipped.
+    #[inline]
     pub fn edit(&mut self, data: &str, begin: u16) {
-        let begin = begin as usize;
-
-        for (i, c) in data.chars().enumerate() {
-            self.data[begin + i] = c;
-        }
+        self.edit_iter(data.chars(), begin);
     }
 
+    /// Writes the characters of `data_iter` starting at column `begin`.
+    /// Anything past the end of the line is clipped.
     pub fn edit_iter<I>(&mut self, data_iter: I, begin: u16) 
     where
         I: Iterator<Item = char>
     {
-        let begin = begin as usize;
-
-        for (i, c) in data_iter.enumerate() {
-            self.data[begin + i] = c;
+        for (cell, c) in self.data.iter_mut().skip(begin as usize).zip(data_iter) {
+            *cell = c;
         }
     }
 
@@ -79,12 +132,232 @@ impl Line {
     pub fn clear(&mut self) {
         self.fill(WHITESPACE_CHAR);
         self.ansi.clear();
+        self.spans.clear();
     }
 
     #[inline]
     pub fn as_string(&self) -> String {
         self.data.iter().collect()
     }
+
+    /// Returns the line content as it should be written to the terminal,
+    /// with trailing whitespace trimmed as `mode` says.
+    pub fn as_output_string(&self, mode: TrimMode) -> String {
+        let content_end = || self.data
+            .iter()
+            .rposition(|c| !c.is_whitespace())
+            .map_or(0, |i| i + 1);
+
+        let len = match mode {
+            TrimMode::None => self.data.len(),
+            TrimMode::TrailingUnstyled if !self.ansi.is_empty() => self.data.len(),
+            TrimMode::TrailingUnstyled => {
+                let spans_end = self.spans.iter().map(|span| span.end).max().unwrap_or(0);
+                spans_end.max(content_end())
+            },
+            TrimMode::Always => content_end(),
+        };
+
+        self.styled_string(len)
+    }
+
+    /// Returns the full width of the line, including the ANSI of its spans.
+    #[inline]
+    pub fn as_styled_string(&self) -> String {
+        if self.spans.is_empty() {
+            return self.as_string();
+        }
+
+        self.styled_string(self.data.len())
+    }
+
+    /// Returns the first `len` characters with the ANSI of the spans inserted.
+    /// The ANSI of the whole line is left to the caller.
+    fn styled_string(&self, len: usize) -> String {
+        if self.spans.is_empty() {
+            return self.data[..len].iter().collect();
+        }
+
+        let restore = format!(
+            "{}{}{}",
+            ansi::ESC_COLOR_RESET, ansi::ESC_STYLE_RESET, self.ansi.concat());
+        let mut buf = String::with_capacity(len + 16 * self.spans.len());
+
+        for (i, c) in self.data[..len].iter().enumerate() {
+            self.close_spans(&mut buf, i, &restore);
+
+            for span in self.spans.iter().filter(|span| span.begin == i) {
+                buf.push_str(&span.ansi.concat());
+
+                if let Some(url) = &span.link {
+                    buf.push_str(&ansi::esc_link_open(url));
+                }
+            }
+
+            buf.push(*c);
+        }
+
+        // Links must be closed even at the end of the line, the ANSI reset
+        // written after the line does not end them.
+        self.close_spans(&mut buf, len, &restore);
+
+        // Including links cut short by trimming.
+        if self.spans.iter().any(|span| span.link.is_some() && span.begin < len && span.end > len) {
+            buf.push_str(ansi::ESC_LINK_CLOSE);
+        }
+
+        buf
+    }
+
+    /// Writes what ends the spans ending at column `i`.
+    fn close_spans(&self, buf: &mut String, i: usize, restore: &str) {
+        let ending = || self.spans.iter().filter(move |span| span.end == i);
+
+        if ending().any(|span| span.link.is_some()) {
+            buf.push_str(ansi::ESC_LINK_CLOSE);
+        }
+
+        if ending().any(|span| !span.ansi.is_empty()) && i < self.data.len() {
+            buf.push_str(restore);
+        }
+    }
+}
+
+/// An `enum` representing the separator written between lines by `Renderer`.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineEnding {
+    /// `\n` only.
+    Lf,
+
+    /// `\r\n`, which positions lines correctly even when the terminal does
+    /// not translate `\n`, such as in raw mode or over a raw socket.
+    #[default]
+    CrLf,
+}
+
+impl LineEnding {
+    #[inline]
+    fn as_str(&self) -> &'static str {
+        match self {
+            LineEnding::Lf => "\n",
+            LineEnding::CrLf => "\r\n",
+        }
+    }
+}
+
+/// An `enum` representing how `Renderer` trims the trailing whitespace of
+/// each line when drawing a frame. Trimming writes less, but leaves whatever
+/// the terminal showed past the end of the line.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TrimMode {
+    /// Lines are always written at full width, so padded backgrounds and
+    /// right-aligned content are kept.
+    None,
+
+    /// Trailing whitespace is trimmed only where it has no style: never on
+    /// lines with ANSI of their own, and never inside a span.
+    #[default]
+    TrailingUnstyled,
+
+    /// Trailing whitespace is always trimmed, styled or not.
+    Always,
+}
+
+/// A terminal a `Renderer` can draw to with `draw_to`: somewhere to write the
+/// frames and a size to follow. `StdoutBackend` is the terminal the program
+/// runs in; implement this to serve a UI over a pseudo-terminal or a socket,
+/// which report their own size.
+pub trait TerminalBackend: Write {
+    /// Returns the size of the terminal as `(width, height)`, in characters.
+    fn size(&self) -> FtuiResult<(u16, u16)>;
+}
+
+/// The terminal the program runs in: frames are written to stdout and the
+/// size is the one of the controlling terminal. What `draw` uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct StdoutBackend;
+
+impl Write for StdoutBackend {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        io::stdout().lock().write(buf)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        io::stdout().lock().flush()
+    }
+}
+
+impl TerminalBackend for StdoutBackend {
+    #[inline]
+    fn size(&self) -> FtuiResult<(u16, u16)> {
+        Ok(ct::terminal::size()?)
+    }
+}
+
+/// Keeps everything written to it in memory and reports a preset size, for
+/// tests and for inspecting frames without a terminal.
+///
+/// # Example
+/// ```rust
+/// let mut backend = MemoryBackend::new(80, 24);
+/// let mut renderer = Renderer::fullscreen_for(&backend)?;
+///
+/// renderer.draw_to(&mut backend, &mut container)?;
+/// assert!(String::from_utf8_lossy(backend.output()).contains("Welcome"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct MemoryBackend {
+    output: Vec<u8>,
+    width: u16,
+    height: u16,
+}
+
+impl MemoryBackend {
+    pub fn new(width: u16, height: u16) -> Self {
+        Self { output: Vec::new(), width, height }
+    }
+
+    /// Returns everything written so far.
+    #[inline]
+    pub fn output(&self) -> &[u8] {
+        &self.output
+    }
+
+    /// Discards everything written so far.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.output.clear();
+    }
+
+    /// Changes the reported size, like a resized terminal.
+    #[inline]
+    pub fn resize(&mut self, width: u16, height: u16) {
+        self.width = width;
+        self.height = height;
+    }
+}
+
+impl Write for MemoryBackend {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.output.write(buf)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl TerminalBackend for MemoryBackend {
+    #[inline]
+    fn size(&self) -> FtuiResult<(u16, u16)> {
+        Ok((self.width, self.height))
+    }
 }
 
 /// A `Renderer` is responsible for rendering the UI to the terminal. It takes 
@@ -98,14 +371,127 @@ pub struct Renderer {
     width: u16,
     height: u16,
     lines: Vec<Line>,
+    origin: Option<(u16, u16)>,
+    min_draw_interval: Option<Duration>,
+    last_draw: Option<Instant>,
+    draw_skipped: bool,
+    line_ending: LineEnding,
+    trim_mode: TrimMode,
+    history: Vec<String>,
+    history_capacity: usize,
+    synchronized_output: bool,
+    fullscreen: bool,
+    auto_fullscreen: bool,
+    previous: Option<Vec<Line>>,
 }
 
 impl Renderer {
+    #[inline]
     pub fn new(dimension: Dimension) -> Renderer {
+        Renderer::headless(dimension.width(), dimension.height())
+    }
+
+    /// Constructs a new `Renderer` covering the whole terminal. The size is
+    /// read once; enable `auto_fullscreen` or call `sync_to_terminal` to
+    /// follow terminal resizes.
+    ///
+    /// # Returns
+    /// - `Ok(Renderer)`: A `Renderer` instance.
+    /// - `Err(FtuiError)`: Returns an error.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut renderer = Renderer::fullscreen()?;
+    /// renderer.auto_fullscreen(true);
+    /// ```
+    pub fn fullscreen() -> FtuiResult<Renderer> {
+        let mut renderer = Renderer::new(Dimension::fullscreen()?);
+        renderer.fullscreen = true;
+        Ok(renderer)
+    }
+
+    /// Same as `fullscreen`, but covers the whole of `backend` instead of the
+    /// terminal the program runs in. Draw with `draw_to`.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut renderer = Renderer::fullscreen_for(&pty)?;
+    /// renderer.auto_fullscreen(true);
+    /// ```
+    pub fn fullscreen_for(backend: &impl TerminalBackend) -> FtuiResult<Renderer> {
+        let (width, height) = backend.size()?;
+        let mut renderer = Renderer::headless(width, height);
+        renderer.fullscreen = true;
+        Ok(renderer)
+    }
+
+    /// Constructs a new `Renderer` covering only the given area of the terminal.
+    /// The buffer is sized to the rectangle, and `draw` writes each line at the
+    /// rectangle's position without clearing the rest of the terminal, so
+    /// several `Renderer`s can share the screen as independent panes.
+    ///
+    /// # Parameters
+    /// - `rect`: The area of the terminal to draw into.
+    ///
+    /// # Returns
+    /// - `Ok(Renderer)`: A `Renderer` instance.
+    /// - `Err(FtuiError)`: Returns an error if the rectangle does not fit in
+    ///   the terminal.
+    ///
+    /// # Example
+    /// ```rust
+    /// // Two side by side panes, each 40 characters wide.
+    /// let mut left = Renderer::for_rect(Rectangle::new(0, 0, 40, 20))?;
+    /// let mut right = Renderer::for_rect(Rectangle::new(40, 0, 40, 20))?;
+    /// ```
+    pub fn for_rect(rect: Rectangle) -> FtuiResult<Renderer> {
+        let (term_width, term_height) = ct::terminal::size()?;
+
+        if rect.x() as u32 + rect.width() as u32 > term_width as u32 ||
+            rect.y() as u32 + rect.height() as u32 > term_height as u32
+        {
+            return Err(FtuiError::DimensionsTerminalToSmall);
+        }
+
+        Ok(Renderer {
+            origin: Some((rect.x(), rect.y())),
+            ..Renderer::headless(rect.width(), rect.height())
+        })
+    }
+
+    /// Constructs a new `Renderer` of the given size that is not tied to a
+    /// terminal. Unlike `new`, no terminal size check is done, so it works
+    /// where no terminal is present (CI, tests, screenshots). Use
+    /// `render_to_plain_string` to get what was rendered.
+    ///
+    /// # Parameters
+    /// - `width`: The width in characters.
+    /// - `height`: The height in characters.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut renderer = Renderer::headless(40, 10);
+    /// let frame = renderer.render_to_plain_string(&mut container)?;
+    ///
+    /// assert!(frame.contains("Welcome"));
+    /// ```
+    pub fn headless(width: u16, height: u16) -> Renderer {
         Renderer {
-            width: dimension.width(),
-            height: dimension.height(),
-            lines: Self::make_lines(dimension.width(), dimension.height()),
+            width,
+            height,
+            lines: Self::make_lines(width, height),
+            origin: None,
+            min_draw_interval: None,
+            last_draw: None,
+            draw_skipped: false,
+            line_ending: LineEnding::CrLf,
+            trim_mode: TrimMode::default(),
+            history: Vec::new(),
+            history_capacity: 0,
+            synchronized_output: false,
+            fullscreen: false,
+            auto_fullscreen: false,
+            previous: None,
         }
     }
 
@@ -113,6 +499,58 @@ impl Renderer {
         (0..height).map(|_| Line::new(width)).collect()
     }
 
+    /// Resizes the `Renderer` buffer to `width` by `height`. The buffer is
+    /// cleared, so render again before drawing.
+    ///
+    /// # Parameters
+    /// - `width`: The new width in characters.
+    /// - `height`: The new height in characters.
+    ///
+    /// # Example
+    /// ```rust
+    /// if let Event::Resize(width, height) = event {
+    ///     renderer.resize(width, height);
+    /// }
+    /// ```
+    pub fn resize(&mut self, width: u16, height: u16) {
+        self.width = width;
+        self.height = height;
+        self.lines = Self::make_lines(width, height);
+    }
+
+    /// Resizes the `Renderer` to the current terminal size if it changed.
+    /// Call it every iteration of the main loop to keep a fullscreen
+    /// `Renderer` correct when the window is resized.
+    ///
+    /// # Returns
+    /// - `Ok(true)`: The `Renderer` was resized.
+    /// - `Ok(false)`: The size did not change.
+    /// - `Err(FtuiError)`: Returns an error.
+    ///
+    /// # Example
+    /// ```rust
+    /// loop {
+    ///     renderer.sync_to_terminal()?;
+    ///     renderer.draw(&mut container)?;
+    /// }
+    /// ```
+    #[inline]
+    pub fn sync_to_terminal(&mut self) -> FtuiResult<bool> {
+        self.sync_to_backend(&StdoutBackend)
+    }
+
+    /// Same as `sync_to_terminal`, but follows the size of `backend`.
+    pub fn sync_to_backend(&mut self, backend: &impl TerminalBackend) -> FtuiResult<bool> {
+        let (width, height) = backend.size()?;
+
+        if (width, height) == (self.width, self.height) {
+            return Ok(false);
+        }
+
+        self.resize(width, height);
+        Ok(true)
+    }
+
     // A static method because it often cause borrow checker problem.
     /// Caculate the position of a middle-aligned component.
     #[inline] 
@@ -121,10 +559,10 @@ impl Renderer {
     }
 
     // A static method because it often cause borrow checker problem.
-    /// Caculate the position of a bottom-aligned component.
+    /// Caculate the position of a bottom-aligned component `rows` lines tall.
     #[inline]
-    fn calc_bottom_align_pos(height: u16) -> u16 {
-        height - 1
+    fn calc_bottom_align_pos(height: u16, rows: u16) -> u16 {
+        height.saturating_sub(rows)
     }
 
     #[inline]
@@ -152,18 +590,11 @@ impl Renderer {
     }
 
     pub(crate) fn render_text_as_footer(
-        &mut self, footer: &mut cpn::Text
+        &mut self, footer: &mut cpn::Text, overflow: OverflowPolicy
     ) -> FtuiResult<()> {
-        self.ensure_label_inbound(footer.len())?;
-        footer.resolve_pos(self.width);
-        footer.set_line(Self::calc_bottom_align_pos(self.height));
-
-        let line = &mut self.lines[footer.line() as usize];
-
-        line.edit(footer.label(), footer.pos());
-        line.add_ansi_many(footer.styles());
-
-        Ok(())
+        let rows = footer.row_count(self.width, overflow);
+        footer.set_line(Self::calc_bottom_align_pos(self.height, rows));
+        footer.render_with(self, overflow)
     }
 
     #[inline]
@@ -172,21 +603,30 @@ impl Renderer {
     }
 
     fn to_string(&self) -> String {
+        let frame = match self.origin {
+            Some((x, y)) => self.to_string_at(x, y),
+            None => self.to_string_full(),
+        };
+
+        if self.synchronized_output {
+            format!("{}{}{}", ansi::ESC_SYNC_BEGIN, frame, ansi::ESC_SYNC_END)
+        } else {
+            frame
+        }
+    }
+
+    /// Composes the frame for a `Renderer` covering the whole terminal.
+    fn to_string_full(&self) -> String {
         let mut buf = String::with_capacity(((self.height * self.width) + 40) as usize);
         let reset_suffix = format!("{}{}", ansi::ESC_COLOR_RESET, ansi::ESC_STYLE_RESET);
 
         buf.push_str(ansi::_ESC_CLEAR_TERM);
 
         for (i, line) in self.lines.iter().enumerate() {
-            let have_ansi = !line.ansi.is_empty();
-            let line_data = line.as_string();
+            let have_ansi = line.has_ansi();
 
             buf.push_str(&line.ansi.concat());
-
-            // Exclude lines containing only whitesapce unless it have ANSIs.
-            if !line_data.trim().is_empty() || have_ansi {
-                buf.push_str(if have_ansi { &line_data } else { &line_data.trim_end() });
-            }
+            buf.push_str(&line.as_output_string(self.trim_mode));
 
             // Only include the ANSI reset suffix if the line have ANSIs.
             if have_ansi {
@@ -194,7 +634,7 @@ impl Renderer {
             }
 
             if i != (self.height - 1) as usize {
-                buf.push_str("\r\n");
+                buf.push_str(self.line_ending.as_str());
             }
         }
 
@@ -202,6 +642,457 @@ impl Renderer {
         buf
     }
     
+    /// Composes the frame for a `Renderer` positioned at (`x`, `y`). Each line
+    /// is moved to its place and written at full width so it overwrites
+    /// whatever the pane showed before.
+    fn to_string_at(&self, x: u16, y: u16) -> String {
+        let mut buf = String::with_capacity(
+            self.height as usize * (self.width as usize + 10) + 40);
+        let reset_suffix = format!("{}{}", ansi::ESC_COLOR_RESET, ansi::ESC_STYLE_RESET);
+
+        for (i, line) in self.lines.iter().enumerate() {
+            Self::push_line_at(&mut buf, line, x, y + i as u16, &reset_suffix);
+        }
+
+        buf
+    }
+
+    /// Writes `line` at (`x`, `y`) into `buf`, at full width so it overwrites
+    /// whatever was there.
+    fn push_line_at(buf: &mut String, line: &Line, x: u16, y: u16, reset_suffix: &str) {
+        buf.push_str(&ansi::esc_cursor_to(x, y));
+        buf.push_str(&line.ansi.concat());
+        buf.push_str(&line.as_styled_string());
+
+        if line.has_ansi() {
+            buf.push_str(reset_suffix);
+        }
+    }
+
+    /// Composes only the lines that changed since the last frame written, or
+    /// the whole frame if there is none or its size differs.
+    fn to_diff_string(&self) -> String {
+        let previous = match &self.previous {
+            Some(previous) if
+                previous.len() == self.lines.len() &&
+                previous.first().map(|line| line.width) == self.lines.first().map(|line| line.width)
+                => previous,
+            _ => return self.to_string(),
+        };
+
+        let (x, y) = self.origin.unwrap_or((0, 0));
+        let reset_suffix = format!("{}{}", ansi::ESC_COLOR_RESET, ansi::ESC_STYLE_RESET);
+        let mut buf = String::new();
+
+        for i in Self::changed_lines(previous, &self.lines) {
+            Self::push_line_at(&mut buf, &self.lines[i], x, y + i as u16, &reset_suffix);
+        }
+
+        if buf.is_empty() {
+            return buf;
+        }
+        if self.origin.is_none() {
+            buf.push_str(ansi::ESC_CURSOR_HOME);
+        }
+
+        if self.synchronized_output {
+            format!("{}{}{}", ansi::ESC_SYNC_BEGIN, buf, ansi::ESC_SYNC_END)
+        } else {
+            buf
+        }
+    }
+
+    /// Returns the indices of the lines whose content or style differ.
+    fn changed_lines<'a>(
+        before: &'a [Line], after: &'a [Line]
+    ) -> impl Iterator<Item = usize> + 'a {
+        before
+            .iter()
+            .zip(after)
+            .enumerate()
+            .filter(|(_, (before, after))| before != after)
+            .map(|(i, _)| i)
+    }
+
+    /// Returns the buffer as plain text without any ANSI, one line per row.
+    pub(crate) fn as_plain_string(&self) -> String {
+        self.lines
+            .iter()
+            .map(|line| line.as_string().trim_end().to_string())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    /// Copies the text currently in the `Renderer` buffer to the system
+    /// clipboard as plain text. This is whatever was last rendered, for example
+    /// the visible part of a `Document`. Only available with the `clipboard`
+    /// feature.
+    ///
+    /// # Returns
+    /// - `Ok(())`: Returns nothing.
+    /// - `Err(FtuiError)`: Returns an error if the clipboard is unavailable.
+    ///
+    /// # Example
+    /// ```rust
+    /// renderer.draw(&mut document)?;
+    ///
+    /// // Copy the visible part of the document.
+    /// renderer.copy_to_clipboard()?;
+    /// ```
+    #[cfg(feature = "clipboard")]
+    pub fn copy_to_clipboard(&self) -> FtuiResult<()> {
+        arboard::Clipboard::new()?.set_text(self.as_plain_string())?;
+        Ok(())
+    }
+
+    /// Renders a renderable into the `Renderer` buffer without writing
+    /// anything to the terminal. Use `flush` to display the buffer.
+    ///
+    /// # Example
+    /// ```rust
+    /// // Render the container, stamp an overlay on top, then display both.
+    /// renderer.render(&mut container)?;
+    /// renderer.put_str(0, 0, "12:00", TextFlags::COLOR_CYAN)?;
+    /// renderer.flush()?;
+    /// ```
+    #[inline]
+    pub fn render<C>(&mut self, renderable: &mut C) -> FtuiResult<()>
+    where 
+        C: RenderableMut<Renderer>
+    {
+        renderable.render(self)
+    }
+
+    /// Renders a renderable into the `Renderer` buffer and returns the buffer
+    /// as plain text, one line per row with trailing whitespace trimmed. No
+    /// ANSI is included and nothing is written to the terminal.
+    ///
+    /// # Returns
+    /// - `Ok(String)`: The rendered text.
+    /// - `Err(FtuiError)`: Returns an error.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut renderer = Renderer::headless(40, 10);
+    ///
+    /// // Compare against a stored snapshot.
+    /// assert_eq!(renderer.render_to_plain_string(&mut container)?, expected);
+    /// ```
+    pub fn render_to_plain_string<C>(&mut self, renderable: &mut C) -> FtuiResult<String>
+    where 
+        C: RenderableMut<Renderer>
+    {
+        self.render(renderable)?;
+        Ok(self.as_plain_string())
+    }
+
+    /// Renders a renderable into the `Renderer` buffer and returns the frame
+    /// exactly as `draw` would write it to the terminal, ANSI included.
+    /// Nothing is written to the terminal.
+    ///
+    /// # Returns
+    /// - `Ok(String)`: The composed frame.
+    /// - `Err(FtuiError)`: Returns an error.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut renderer = Renderer::headless(40, 10);
+    ///
+    /// // Snapshot the styled output.
+    /// assert_eq!(renderer.render_to_string(&mut container)?, expected);
+    /// ```
+    pub fn render_to_string<C>(&mut self, renderable: &mut C) -> FtuiResult<String>
+    where 
+        C: RenderableMut<Renderer>
+    {
+        self.render(renderable)?;
+        Ok(self.to_string())
+    }
+
+    /// Renders a renderable into the `Renderer` buffer and writes the frame to
+    /// `writer` instead of the terminal. Useful for logging frames to a file.
+    ///
+    /// # Parameters
+    /// - `renderable`: The object to render.
+    /// - `writer`: Where the frame is written.
+    ///
+    /// # Returns
+    /// - `Ok(())`: Returns nothing.
+    /// - `Err(FtuiError)`: Returns an error.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut log = File::create("frames.log")?;
+    /// renderer.render_to_writer(&mut container, &mut log)?;
+    /// ```
+    pub fn render_to_writer<C, W>(
+        &mut self, renderable: &mut C, writer: &mut W
+    ) -> FtuiResult<()>
+    where 
+        C: RenderableMut<Renderer>,
+        W: Write,
+    {
+        self.render(renderable)?;
+        self.flush_to(writer)
+    }
+
+    /// Writes the current `Renderer` buffer to the terminal without
+    /// re-rendering anything.
+    ///
+    /// # Returns
+    /// - `Ok(())`: Returns nothing.
+    /// - `Err(FtuiError)`: Returns an error.
+    #[inline]
+    pub fn flush(&mut self) -> FtuiResult<()> {
+        self.flush_to(&mut std::io::stdout().lock())
+    }
+
+    /// Writes the current `Renderer` buffer to `writer`, recording it in the
+    /// frame history.
+    #[inline]
+    fn flush_to(&mut self, writer: &mut impl Write) -> FtuiResult<()> {
+        let frame = self.to_string();
+        self.write_frame(writer, &frame)
+    }
+
+    /// Writes only line `row` of the buffer to `writer`, in place, without
+    /// clearing the terminal or recording a frame. A row outside the buffer
+    /// writes nothing.
+    pub(crate) fn flush_line_to(&self, writer: &mut impl Write, row: u16) -> FtuiResult<()> {
+        let Some(line) = self.lines.get(row as usize) else {
+            return Ok(());
+        };
+
+        let (x, y) = self.origin.unwrap_or((0, 0));
+        let reset_suffix = format!("{}{}", ansi::ESC_COLOR_RESET, ansi::ESC_STYLE_RESET);
+        let mut buf = String::new();
+
+        Self::push_line_at(&mut buf, line, x, y + row, &reset_suffix);
+
+        if self.origin.is_none() {
+            buf.push_str(ansi::ESC_CURSOR_HOME);
+        }
+
+        writer.write_all(buf.as_bytes())?;
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    /// Writes a composed frame of the buffer to `writer`, recording the
+    /// buffer in the frame history and, once `draw_diff` is used, as the last
+    /// frame written.
+    fn write_frame(&mut self, writer: &mut impl Write, frame: &str) -> FtuiResult<()> {
+        if self.history_capacity != 0 {
+            if self.history.len() == self.history_capacity {
+                self.history.remove(0);
+            }
+            self.history.push(self.as_plain_string());
+        }
+
+        writer.write_all(frame.as_bytes())?;
+        writer.flush()?;
+
+        if self.previous.is_some() {
+            self.previous = Some(self.lines.clone());
+        }
+
+        Ok(())
+    }
+
+    /// Writes a styled string directly into the `Renderer` buffer at column `x`
+    /// of line `y`, without building a component. Text that runs past the right
+    /// edge is clipped, and a position outside the buffer writes nothing.
+    ///
+    /// # Notes
+    /// - Alignment flags are ignored, the text always starts at `x`.
+    /// - Containers clear the buffer when rendered, so call this after `render`
+    ///   and before `flush`.
+    ///
+    /// # Parameters
+    /// - `x`: The column to start writing at.
+    /// - `y`: The line to write on.
+    /// - `text`: The text to write.
+    /// - `flags`: An optional set of `TextFlags` used to style the text.
+    ///
+    /// # Returns
+    /// - `Ok(())`: Returns nothing.
+    /// - `Err(FtuiError)`: Returns an error if the flags are incompatible.
+    ///
+    /// # Example
+    /// ```rust
+    /// // Show a red clock in the top left corner.
+    /// renderer.render(&mut container)?;
+    /// renderer.put_str(0, 0, "12:00", TextFlags::COLOR_RED)?;
+    /// renderer.flush()?;
+    /// ```
+    pub fn put_str(
+        &mut self,
+        x: u16, y: u16, text: &str, flags: impl Into<Option<cpn::TextFlags>>
+    ) -> FtuiResult<()> {
+        let flags = flags.into().unwrap_or(cpn::TextFlags::NONE);
+        flags.ensure_compatibility()?;
+
+        if x >= self.width || y >= self.height {
+            return Ok(());
+        }
+
+        let line = &mut self.lines[y as usize];
+        let written = text.chars().count().min((self.width - x) as usize);
+
+        // Only the written cells are styled, the rest of the line is kept.
+        line.edit_iter(text.chars().take(written), x);
+        line.add_span(x as usize, written, flags.resolve_ansi());
+
+        Ok(())
+    }
+
+    /// Clears the screen and draws `text` centered horizontally and
+    /// vertically, without building a `Message` or container. Each line of a
+    /// multi-line `text` is centered on its own. The simplest screen there is,
+    /// for empty states or "Press any key".
+    ///
+    /// # Notes
+    /// - Like a `Text`, the style only covers the text unless `flags`
+    ///   contains `TextFlags::FILL_LINE`. Alignment flags are ignored.
+    /// - Lines wider than the `Renderer` are clipped, and lines past its
+    ///   height are dropped.
+    ///
+    /// # Parameters
+    /// - `text`: The text to draw.
+    /// - `flags`: The `TextFlags` to style it with.
+    ///
+    /// # Returns
+    /// - `Ok(())`: Returns nothing.
+    /// - `Err(FtuiError)`: Returns an error.
+    ///
+    /// # Example
+    /// ```rust
+    /// renderer.draw_centered_text("No results\nPress any key", TextFlags::STYLE_DIM)?;
+    /// ```
+    pub fn draw_centered_text(
+        &mut self, text: &str, flags: impl Into<Option<cpn::TextFlags>>
+    ) -> FtuiResult<()> {
+        let flags = flags.into().unwrap_or(cpn::TextFlags::NONE);
+        flags.ensure_compatibility()?;
+
+        self.draw(&mut CenteredText { text, flags })
+    }
+
+    /// Writes a styled string vertically into the `Renderer` buffer, one
+    /// grapheme per line going down from line `y` in column `x`, for example
+    /// for a chart axis label. Text that runs past the bottom edge is clipped,
+    /// and a position outside the buffer writes nothing.
+    ///
+    /// # Notes
+    /// - Each cell holds one character, so a grapheme made of several (such
+    ///   as one with combining marks) fills one cell per character, like in
+    ///   `put_str`.
+    /// - A wide grapheme, such as a CJK character, would spill into the next
+    ///   column, so it is replaced with U+FFFD.
+    /// - Call this after `render` and before `flush`, like `put_str`.
+    ///
+    /// # Parameters
+    /// - `x`: The column to write in.
+    /// - `y`: The line to start writing on.
+    /// - `text`: The text to write.
+    /// - `flags`: An optional set of `TextFlags` used to style the text.
+    ///
+    /// # Returns
+    /// - `Ok(())`: Returns nothing.
+    /// - `Err(FtuiError)`: Returns an error if the flags are incompatible.
+    ///
+    /// # Example
+    /// ```rust
+    /// // Label the y axis of a chart.
+    /// renderer.put_vstr(0, 2, "Load", TextFlags::STYLE_DIM)?;
+    /// ```
+    pub fn put_vstr(
+        &mut self,
+        x: u16, y: u16, text: &str, flags: impl Into<Option<cpn::TextFlags>>
+    ) -> FtuiResult<()> {
+        let flags = flags.into().unwrap_or(cpn::TextFlags::NONE);
+        flags.ensure_compatibility()?;
+
+        if x >= self.width || y >= self.height {
+            return Ok(());
+        }
+
+        let style = flags.resolve_ansi();
+        let x = x as usize;
+
+        for (line, grapheme) in self.lines[y as usize..].iter_mut().zip(text.graphemes(true)) {
+            let cells = if util::display_width(grapheme) > 1 {
+                line.data[x] = REPLACEMENT_CHAR;
+                1
+            } else {
+                line.data[x..]
+                    .iter_mut()
+                    .zip(grapheme.chars())
+                    .map(|(cell, c)| *cell = c)
+                    .count()
+            };
+
+            if !style.is_empty() {
+                line.add_span(x, cells, style.clone());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Draws a progress bar covering only part of line `row`, from column
+    /// `start` up to, but not including, column `end`. Useful for small bars
+    /// inside a list or table row. The filled part is drawn with `█` and the
+    /// rest with `░`; the span is clipped to the `Renderer` width.
+    ///
+    /// # Notes
+    /// - Only the bar is styled, the rest of the line keeps its style.
+    /// - Call this after `render` and before `flush`, like `put_str`.
+    ///
+    /// # Parameters
+    /// - `row`: The line to draw on.
+    /// - `start`: The first column of the bar.
+    /// - `end`: The column after the last column of the bar.
+    /// - `fraction`: How full the bar is, from 0.0 to 1.0. Values outside that
+    ///   range are clamped.
+    /// - `flags`: An optional set of `TextFlags` used to style the bar.
+    ///
+    /// # Returns
+    /// - `Ok(())`: Returns nothing.
+    /// - `Err(FtuiError)`: Returns an error if the flags are incompatible.
+    ///
+    /// # Example
+    /// ```rust
+    /// // A green bar, 60% full, in columns 10 to 19.
+    /// renderer.put_progress(2, 10, 20, 0.6, TextFlags::COLOR_GREEN)?;
+    /// ```
+    pub fn put_progress(
+        &mut self,
+        row: u16, start: u16, end: u16, fraction: f32,
+        flags: impl Into<Option<cpn::TextFlags>>,
+    ) -> FtuiResult<()> {
+        let flags = flags.into().unwrap_or(cpn::TextFlags::NONE);
+        flags.ensure_compatibility()?;
+
+        let end = end.min(self.width);
+
+        if row >= self.height || start >= end {
+            return Ok(());
+        }
+
+        let len = (end - start) as usize;
+        let filled = (fraction.clamp(0.0, 1.0) * len as f32).round() as usize;
+        let line = &mut self.lines[row as usize];
+
+        line.edit_iter(
+            std::iter::repeat_n('█', filled).chain(std::iter::repeat_n('░', len - filled)),
+            start);
+        line.add_span(start as usize, len, flags.resolve_ansi());
+
+        Ok(())
+    }
+    
     /// Draws the `Renderer` buffer to the terminal.
     ///
     /// # Note
@@ -230,16 +1121,417 @@ impl Renderer {
     /// // but changes won't be reflected unless `render` is called.
     /// renderer.draw();
     /// ```
+    #[inline]
     pub fn draw<C>(&mut self, renderable: &mut C) -> FtuiResult<()>
     where 
         C: RenderableMut<Renderer>
     {
-        renderable.render(self)?;
+        self.draw_to(&mut StdoutBackend, renderable)
+    }
+
+    /// Same as `draw`, but draws to `backend` instead of the terminal the
+    /// program runs in, such as a pseudo-terminal served over SSH. With
+    /// `auto_fullscreen`, the `Renderer` follows the size of `backend`.
+    ///
+    /// # Parameters
+    /// - `backend`: The terminal to draw to.
+    /// - `renderable`: The object to draw.
+    ///
+    /// # Returns
+    /// - `Ok(())`: Returns nothing.
+    /// - `Err(FtuiError)`: Returns an error.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut renderer = Renderer::fullscreen_for(&pty)?;
+    /// renderer.auto_fullscreen(true);
+    ///
+    /// loop {
+    ///     renderer.draw_to(&mut pty, &mut container)?;
+    ///     ...
+    /// }
+    /// ```
+    pub fn draw_to<C>(
+        &mut self, backend: &mut impl TerminalBackend, renderable: &mut C
+    ) -> FtuiResult<()>
+    where 
+        C: RenderableMut<Renderer>
+    {
+        if !self.begin_draw(backend)? {
+            return Ok(());
+        }
+
+        self.render_to_writer(renderable, backend)?;
+        self.end_draw();
+
+        Ok(())
+    }
+
+    /// Same as `draw`, but only rewrites the lines whose content or style
+    /// changed since the last frame written to the terminal, which reduces
+    /// flicker on large terminals and bandwidth over SSH. The whole frame is
+    /// written on the first call and whenever the size of the `Renderer`
+    /// changed.
+    ///
+    /// # Notes
+    /// - The terminal is assumed to still show the last frame written. If
+    ///   something else wrote over it, use `draw` once to repaint everything.
+    ///
+    /// # Example
+    /// ```rust
+    /// loop {
+    ///     // Only the lines that changed are written.
+    ///     renderer.draw_diff(&mut container)?;
+    ///     ...
+    /// }
+    /// ```
+    #[inline]
+    pub fn draw_diff<C>(&mut self, renderable: &mut C) -> FtuiResult<()>
+    where 
+        C: RenderableMut<Renderer>
+    {
+        self.draw_diff_to(&mut StdoutBackend, renderable)
+    }
+
+    /// Same as `draw_diff`, but draws to `backend` like `draw_to`.
+    pub fn draw_diff_to<C>(
+        &mut self, backend: &mut impl TerminalBackend, renderable: &mut C
+    ) -> FtuiResult<()>
+    where 
+        C: RenderableMut<Renderer>
+    {
+        if !self.begin_draw(backend)? {
+            return Ok(());
+        }
+
+        self.render(renderable)?;
 
-        let mut stdout = std::io::stdout().lock();
-        stdout.write_all(self.to_string().as_bytes())?;
-        stdout.flush()?;
+        let frame = self.to_diff_string();
+        // Track the frames written from now on.
+        self.previous.get_or_insert_with(Vec::new);
+        self.write_frame(backend, &frame)?;
+        self.end_draw();
 
         Ok(())
     }
+
+    /// Prepares a `draw`, following the size of `backend` if `auto_fullscreen`
+    /// is enabled.
+    ///
+    /// # Returns
+    /// - `Ok(false)`: The draw is skipped by `set_min_draw_interval`.
+    /// - `Ok(true)`: The draw should go ahead.
+    /// - `Err(FtuiError)`: Returns an error.
+    fn begin_draw(&mut self, backend: &impl TerminalBackend) -> FtuiResult<bool> {
+        if let (Some(interval), Some(last)) = (self.min_draw_interval, self.last_draw)
+            && last.elapsed() < interval
+        {
+            self.draw_skipped = true;
+            return Ok(false);
+        }
+
+        if self.fullscreen && self.auto_fullscreen {
+            self.sync_to_backend(backend)?;
+        }
+
+        Ok(true)
+    }
+
+    #[inline]
+    fn end_draw(&mut self) {
+        self.last_draw = Some(Instant::now());
+        self.draw_skipped = false;
+    }
+
+    /// Renders and draws `renderable`, then blocks until a key is pressed.
+    /// Packages the "press any key to continue" pattern of splash and error
+    /// screens into one call.
+    ///
+    /// # Parameters
+    /// - `renderable`: The object to draw.
+    ///
+    /// # Returns
+    /// - `Ok(KeyCode)`: The key that was pressed.
+    /// - `Err(FtuiError)`: Returns an error.
+    ///
+    /// # Notes
+    /// - The frame is always drawn, even within `set_min_draw_interval`.
+    /// - Raw mode is enabled while waiting for the key and restored afterward.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut message = Message::new("Failed to load the save file", MessageStyle::Error);
+    ///
+    /// // Show the error until the user presses something.
+    /// renderer.draw_and_wait(&mut message)?;
+    /// ```
+    pub fn draw_and_wait<C>(&mut self, renderable: &mut C) -> FtuiResult<ct::event::KeyCode>
+    where 
+        C: RenderableMut<Renderer>
+    {
+        // Stay in raw mode for the whole wait so key releases and anything
+        // typed meanwhile are not echoed by the terminal.
+        let was_raw = ct::terminal::is_raw_mode_enabled()?;
+        ct::terminal::enable_raw_mode()?;
+
+        let key = self.draw_and_wait_from(&mut TerminalEventSource, renderable);
+
+        if !was_raw {
+            ct::terminal::disable_raw_mode()?;
+        }
+
+        key
+    }
+
+    /// Same as `draw_and_wait`, but reads the key press from `source`.
+    pub fn draw_and_wait_from<C>(
+        &mut self, source: &mut impl EventSource, renderable: &mut C
+    ) -> FtuiResult<ct::event::KeyCode>
+    where 
+        C: RenderableMut<Renderer>
+    {
+        self.render(renderable)?;
+        self.flush()?;
+        self.end_draw();
+
+        input::wait_for_key_from(source)
+    }
+
+    /// Sets the minimum time between two `draw` calls. A `draw` made sooner
+    /// than that after the last one is skipped entirely, nothing is rendered
+    /// or written. This guards against bursts of redraws hammering the
+    /// terminal. Unlike sleeping in the main loop, excess draws are dropped.
+    ///
+    /// # Parameters
+    /// - `interval`: The minimum interval, or `None` to disable the limit
+    ///   (the default).
+    ///
+    /// # Example
+    /// ```rust
+    /// // Draw at most about 60 times per second.
+    /// renderer.set_min_draw_interval(Duration::from_millis(16));
+    /// ```
+    #[inline]
+    pub fn set_min_draw_interval(&mut self, interval: impl Into<Option<Duration>>) {
+        self.min_draw_interval = interval.into();
+    }
+
+    /// Makes a `Renderer` built with `fullscreen` or `fullscreen_for` re-read
+    /// the terminal size at the start of every `draw`, resizing its buffer
+    /// when the terminal was resized. Has no effect on other `Renderer`s.
+    ///
+    /// # Parameters
+    /// - `enable`: Whether to follow the terminal size. Defaults to `false`.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut renderer = Renderer::fullscreen()?;
+    /// renderer.auto_fullscreen(true);
+    ///
+    /// loop {
+    ///     // Always drawn at the current terminal size.
+    ///     renderer.draw(&mut container)?;
+    /// }
+    /// ```
+    #[inline]
+    pub fn auto_fullscreen(&mut self, enable: bool) {
+        self.auto_fullscreen = enable;
+    }
+
+    /// Sets the separator written between lines when drawing.
+    ///
+    /// # Parameters
+    /// - `line_ending`: The `LineEnding` to use. Defaults to `LineEnding::CrLf`.
+    ///
+    /// # Example
+    /// ```rust
+    /// renderer.set_line_ending(LineEnding::Lf);
+    /// ```
+    #[inline]
+    pub fn set_line_ending(&mut self, line_ending: LineEnding) {
+        self.line_ending = line_ending;
+    }
+
+    /// Sets how the trailing whitespace of each line is trimmed when drawing.
+    ///
+    /// # Notes
+    /// - `Renderer`s built with `for_rect` and frames written by `draw_diff`
+    ///   always write lines at full width, to overwrite what was there.
+    ///
+    /// # Parameters
+    /// - `mode`: The `TrimMode` to use. Defaults to `TrimMode::TrailingUnstyled`.
+    ///
+    /// # Example
+    /// ```rust
+    /// // Keep the padding of right-aligned content.
+    /// renderer.set_trim_mode(TrimMode::None);
+    /// ```
+    #[inline]
+    pub fn set_trim_mode(&mut self, mode: TrimMode) {
+        self.trim_mode = mode;
+    }
+
+    /// Wraps every frame in the synchronized update escapes, so terminals that
+    /// support them show the whole frame at once instead of as it arrives,
+    /// which removes tearing. Other terminals ignore the escapes. Off by
+    /// default.
+    ///
+    /// # Parameters
+    /// - `enabled`: Whether to use synchronized output.
+    ///
+    /// # Example
+    /// ```rust
+    /// renderer.set_synchronized_output(true);
+    /// ```
+    #[inline]
+    pub fn set_synchronized_output(&mut self, enabled: bool) {
+        self.synchronized_output = enabled;
+    }
+
+    /// Keeps the last `capacity` frames written to the terminal as plain text,
+    /// for debugging the output over time (flicker, layout jumps) or dumping
+    /// a session. Off by default, as it copies every frame.
+    ///
+    /// # Parameters
+    /// - `capacity`: How many frames to keep, or 0 to turn the history off
+    ///   and drop it.
+    ///
+    /// # Example
+    /// ```rust
+    /// renderer.set_frame_history(10);
+    /// ```
+    pub fn set_frame_history(&mut self, capacity: usize) {
+        self.history_capacity = capacity;
+
+        if self.history.len() > capacity {
+            self.history.drain(..self.history.len() - capacity);
+        }
+    }
+
+    /// Returns the frames kept by `set_frame_history`, oldest first.
+    ///
+    /// # Example
+    /// ```rust
+    /// // Compare the last two frames.
+    /// if let [.., previous, current] = renderer.frame_history() {
+    ///     assert_eq!(previous, current);
+    /// }
+    /// ```
+    #[inline]
+    pub fn frame_history(&self) -> &[String] {
+        &self.history
+    }
+
+    /// Returns whether the last `draw` call was skipped because of the minimum
+    /// draw interval.
+    #[inline]
+    pub fn draw_skipped(&self) -> bool {
+        self.draw_skipped
+    }
+}
+
+/// The screen drawn by `Renderer::draw_centered_text`.
+struct CenteredText<'a> {
+    text: &'a str,
+    flags: cpn::TextFlags,
+}
+
+impl RenderableMut<Renderer> for CenteredText<'_> {
+    fn render(&mut self, renderer: &mut Renderer) -> FtuiResult<()> {
+        let (width, height) = renderer.get_dimensions();
+        let style = self.flags.resolve_ansi();
+        let rows: Vec<&str> = self.text.lines().collect();
+        let top = (height as usize).saturating_sub(rows.len()) / 2;
+
+        renderer.clear();
+
+        for (y, row) in (top..height as usize).zip(rows) {
+            // Measured in cells, like the buffer writes them.
+            let len = row.chars().count().min(width as usize);
+            let x = Renderer::calc_middle_align_pos(width, len);
+            let line = renderer.line_mut(y);
+
+            line.edit(row, x);
+
+            if self.flags.contains(cpn::TextFlags::FILL_LINE) {
+                line.add_ansi_many(&style);
+            } else {
+                line.add_span(x as usize, len, style.clone());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Writes one string per line.
+    struct Rows(Vec<&'static str>);
+
+    impl RenderableMut<Renderer> for Rows {
+        fn render(&mut self, renderer: &mut Renderer) -> FtuiResult<()> {
+            renderer.clear();
+
+            for (y, row) in self.0.iter().enumerate() {
+                renderer.put_str(0, y as u16, row, None)?;
+            }
+
+            Ok(())
+        }
+    }
+
+    fn take_output(backend: &mut MemoryBackend) -> String {
+        let output = String::from_utf8_lossy(backend.output()).into_owned();
+        backend.clear();
+        output
+    }
+
+    #[test]
+    fn changed_lines_compares_content_and_style() {
+        let before = Renderer::make_lines(5, 3);
+        let mut after = before.clone();
+
+        after[0].edit("a", 0);
+        after[2].add_span(0, 1, cpn::TextFlags::COLOR_RED.resolve_ansi());
+
+        assert_eq!(Renderer::changed_lines(&before, &after).collect::<Vec<_>>(), [0, 2]);
+    }
+
+    #[test]
+    fn draw_diff_writes_only_changed_lines() {
+        let mut backend = MemoryBackend::new(10, 3);
+        let mut renderer = Renderer::headless(10, 3);
+
+        renderer.draw_diff_to(&mut backend, &mut Rows(vec!["one", "two", "three"])).unwrap();
+        assert!(take_output(&mut backend).starts_with(ansi::_ESC_CLEAR_TERM));
+
+        renderer.draw_diff_to(&mut backend, &mut Rows(vec!["one", "two", "three"])).unwrap();
+        assert_eq!(take_output(&mut backend), "");
+
+        renderer.draw_diff_to(&mut backend, &mut Rows(vec!["one", "2", "three"])).unwrap();
+        let output = take_output(&mut backend);
+
+        assert!(output.starts_with(&ansi::esc_cursor_to(0, 1)));
+        assert!(output.contains('2'));
+        assert!(!output.contains("one") && !output.contains("three"));
+    }
+
+    #[test]
+    fn frame_history_keeps_every_draw() {
+        let mut backend = MemoryBackend::new(10, 2);
+        let mut renderer = Renderer::headless(10, 2);
+        renderer.set_frame_history(5);
+
+        for row in ["one", "two", "three"] {
+            renderer.draw_to(&mut backend, &mut Rows(vec![row])).unwrap();
+        }
+
+        assert_eq!(renderer.frame_history(), ["one\n", "two\n", "three\n"]);
+
+        renderer.set_frame_history(2);
+        assert_eq!(renderer.frame_history(), ["two\n", "three\n"]);
+    }
 }
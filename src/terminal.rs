@@ -9,6 +9,9 @@ use crate::error::FtuiResult;
 /// It clears the terminal screen and moves the cursor to the home position,
 /// then hide it. This ensure a clean state before rendering.
 ///
+/// Bracketed paste is also enabled, so pasted text arrives as a single
+/// `Event::Paste` instead of a burst of key presses.
+///
 /// # Returns
 /// - `Ok(())` if the operation completes successfully.
 /// - `Err(FtuiError)` if an error occurs during the operation.
@@ -29,7 +32,8 @@ pub fn ready() -> FtuiResult<()> {
         io::stdout(),
         ct::terminal::EnterAlternateScreen,
         ct::terminal::Clear(ct::terminal::ClearType::All),
-        ct::cursor::MoveTo(0, 0), ct::cursor::Hide)?;
+        ct::cursor::MoveTo(0, 0), ct::cursor::Hide,
+        ct::event::EnableBracketedPaste)?;
 
     Ok(())
 }
@@ -57,6 +61,7 @@ pub fn unready() -> FtuiResult<()> {
     ct::terminal::disable_raw_mode()?;
     ct::execute!(
         io::stdout(),
+        ct::event::DisableBracketedPaste,
         ct::cursor::Show,
         ct::terminal::LeaveAlternateScreen)?;
 
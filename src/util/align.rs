@@ -0,0 +1,29 @@
+/// An `enum` describing where a container places its block of components
+/// when the `Renderer` is taller than the block.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VerticalAlign {
+    /// The block starts at the first line.
+    #[default]
+    Top,
+
+    /// The block is centered, rounding towards the top.
+    Center,
+
+    /// The block ends at the last line, above the footer if there is one.
+    Bottom,
+}
+
+impl VerticalAlign {
+    /// Returns the first line of a block `len` lines tall placed in `height`
+    /// lines.
+    pub(crate) fn offset(self, height: u16, len: u16) -> u16 {
+        let free = height.saturating_sub(len);
+
+        match self {
+            VerticalAlign::Top => 0,
+            VerticalAlign::Center => free / 2,
+            VerticalAlign::Bottom => free,
+        }
+    }
+}
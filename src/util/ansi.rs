@@ -1,3 +1,9 @@
+use std::borrow::Cow;
+
+/// An ANSI escape sequence, either one of the constants below or one built at
+/// runtime (true color, hyperlinks).
+pub(crate) type Ansi = Cow<'static, str>;
+
 // foreground text color
 pub(crate) const ESC_BLACK_F: &str = "\x1b[30m";
 pub(crate) const ESC_RED_F: &str = "\x1b[31m";
@@ -25,7 +31,7 @@ pub(crate) const ESC_DIM: &str = "\x1b[2m";
 pub(crate) const ESC_ITALIC: &str = "\x1b[3m";
 pub(crate) const ESC_UNDERLINE: &str = "\x1b[4m";
 pub(crate) const _ESC_BLINK: &str = "\x1b[5m";
-pub(crate) const _ESC_REVERSED: &str = "\x1b[7m";
+pub(crate) const ESC_REVERSED: &str = "\x1b[7m";
 pub(crate) const _ESC_HIDDEN: &str = "\x1b[8m";
 pub(crate) const ESC_STRIKETHROUGH: &str = "\x1b[9m";
 pub(crate) const _ESC_DOUBLE_UNDERLINE: &str = "\x1b[21m";
@@ -37,5 +43,134 @@ pub(crate) const ESC_CURSOR_HOME: &str = "\x1b[H";
 pub(crate) const _ESC_CURSOR_HIDE: &str = "\x1b[?25l";
 pub(crate) const _ESC_CURSOR_SHOW: &str = "\x1b[?25h";
 
+/// Moves the cursor to the zero-based column `x` and line `y`.
+pub(crate) fn esc_cursor_to(x: u16, y: u16) -> String {
+    format!("\x1b[{};{}H", y + 1, x + 1)
+}
+
+// hyperlinks (OSC 8)
+pub(crate) const ESC_LINK_CLOSE: &str = "\x1b]8;;\x1b\\";
+
+/// Starts a hyperlink to `url`, ended by `ESC_LINK_CLOSE`.
+pub(crate) fn esc_link_open(url: &str) -> String {
+    format!("\x1b]8;;{}\x1b\\", url)
+}
+
 // terminal
 pub(crate) const _ESC_CLEAR_TERM: &str = "\x1b[2J";
+pub(crate) const ESC_SYNC_BEGIN: &str = "\x1b[?2026h";
+pub(crate) const ESC_SYNC_END: &str = "\x1b[?2026l";
+
+/// Returns the escape sequence starting at the beginning of `text`, which
+/// starts with `ESC`. CSI sequences end at their final byte and OSC sequences
+/// at `BEL` or `ST`, any other escape is two characters long.
+fn escape_len(text: &str) -> usize {
+    let mut chars = text.char_indices().skip(1);
+
+    match chars.next() {
+        Some((_, '[')) => chars
+            .find(|(_, c)| ('\x40'..='\x7e').contains(c))
+            .map_or(text.len(), |(i, c)| i + c.len_utf8()),
+        Some((_, ']')) => {
+            let mut previous = '\0';
+
+            chars
+                .find(|&(_, c)| {
+                    let end = c == '\x07' || (previous == '\x1b' && c == '\\');
+                    previous = c;
+                    end
+                })
+                .map_or(text.len(), |(i, c)| i + c.len_utf8())
+        }
+        Some((i, c)) => i + c.len_utf8(),
+        None => text.len(),
+    }
+}
+
+/// Splits `text` into its escape sequences and the visible text between
+/// them, in order. `true` marks an escape sequence.
+fn tokens(mut text: &str) -> impl Iterator<Item = (bool, &str)> {
+    std::iter::from_fn(move || {
+        if text.is_empty() {
+            return None;
+        }
+
+        let len = match text.find('\x1b') {
+            Some(0) => escape_len(text),
+            Some(i) => i,
+            None => text.len(),
+        };
+        let (token, rest) = text.split_at(len);
+        text = rest;
+
+        Some((token.starts_with('\x1b'), token))
+    })
+}
+
+/// Removes every ANSI escape sequence (colors, styles, cursor movement,
+/// hyperlinks, ...) from `text`, leaving only what is visible.
+///
+/// # Parameters
+/// - `text`: A string that may contain escape sequences.
+///
+/// # Returns
+/// `String`: The visible text.
+///
+/// # Example
+/// ```rust
+/// assert_eq!(ansi::strip("\x1b[31mred\x1b[0m text"), "red text");
+/// ```
+pub fn strip(text: &str) -> String {
+    tokens(text)
+        .filter(|(escape, _)| !escape)
+        .map(|(_, visible)| visible)
+        .collect()
+}
+
+/// Returns the number of terminal columns `text` takes, ignoring ANSI escape
+/// sequences. Wide characters such as CJK take two columns.
+///
+/// # Example
+/// ```rust
+/// assert_eq!(ansi::visible_width("\x1b[1m\x1b[32mOK\x1b[0m"), 2);
+/// ```
+pub fn visible_width(text: &str) -> usize {
+    crate::util::display_width(&strip(text))
+}
+
+/// Splits `text` styled with SGR escapes (colors and styles) into its visible
+/// text and the runs of it sharing a style, as `(begin, len, style)` counted
+/// in characters. Runs cover the whole text, unstyled ones have no style.
+/// Other escape sequences are dropped.
+pub(crate) fn parse_styled(text: &str) -> (String, Vec<(usize, usize, Vec<Ansi>)>) {
+    let mut visible = String::new();
+    let mut runs: Vec<(usize, usize, Vec<Ansi>)> = vec![];
+    let mut style: Vec<Ansi> = vec![];
+    let mut len = 0;
+
+    for (escape, token) in tokens(text) {
+        if escape {
+            // Only SGR (`ESC [ ... m`) sequences style the text.
+            if token.starts_with("\x1b[") && token.ends_with('m') {
+                if token == "\x1b[m" || token == ESC_COLOR_RESET {
+                    style.clear();
+                } else {
+                    style.push(Ansi::Owned(token.to_string()));
+                }
+            }
+            continue;
+        }
+
+        let count = token.chars().count();
+        visible.push_str(token);
+
+        match runs.last_mut() {
+            Some((_, run_len, run_style)) if *run_style == style => *run_len += count,
+            _ => runs.push((len, count, style.clone())),
+        }
+
+        len += count;
+    }
+
+    (visible, runs)
+}
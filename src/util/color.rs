@@ -1,4 +1,5 @@
 use crate::util::ansi;
+use crate::util::ansi::Ansi;
 
 #[repr(u8)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -30,7 +31,7 @@ impl Colors {
             Self::RedFore => ESC_RED_F,
             Self::GreenFore => ESC_GREEN_F,
             Self::YellowFore => ESC_YELLOW_F,
-            Self::BlueFore => ESC_BLACK_F,
+            Self::BlueFore => ESC_BLUE_F,
             Self::MagentaFore => ESC_MAGENTA_F,
             Self::CyanFore => ESC_CYAN_F,
             Self::WhiteFore => ESC_WHITE_F,
@@ -38,10 +39,80 @@ impl Colors {
             Self::RedBack => ESC_RED_B,
             Self::GreenBack => ESC_GREEN_B,
             Self::YellowBack => ESC_YELLOW_B,
-            Self::BlueBack => ESC_BLACK_B,
+            Self::BlueBack => ESC_BLUE_B,
             Self::MagentaBack => ESC_MAGENTA_B,
             Self::CyanBack => ESC_CYAN_B,
             Self::WhiteBack => ESC_WHITE_B,
         }
     }
 }
+
+/// A color for text, from the 16 standard terminal colors up to 24-bit true
+/// color. Not every terminal supports `Indexed` and `Rgb`; those that do not
+/// usually pick the closest color they have.
+///
+/// # Example
+/// ```rust
+/// text.set_fg(Color::Rgb(120, 200, 40));
+/// text.set_bg(Color::Indexed(236));
+///
+/// // `Colors` convert to the matching standard color.
+/// text.set_fg(Colors::RedFore);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    /// One of the 16 standard colors: 0 to 7 are black, red, green, yellow,
+    /// blue, magenta, cyan and white, and 8 to 15 their bright variants.
+    /// Larger values wrap around.
+    Ansi16(u8),
+
+    /// A color from the 256 color palette.
+    Indexed(u8),
+
+    /// A 24-bit true color.
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    /// Returns the escape sequence setting this color as the foreground.
+    pub(crate) fn fg_ansi(self) -> Ansi {
+        use ansi::*;
+
+        const BASE: [&str; 8] = [
+            ESC_BLACK_F, ESC_RED_F, ESC_GREEN_F, ESC_YELLOW_F,
+            ESC_BLUE_F, ESC_MAGENTA_F, ESC_CYAN_F, ESC_WHITE_F,
+        ];
+
+        match self {
+            Color::Ansi16(n) if n % 16 < 8 => Ansi::Borrowed(BASE[(n % 8) as usize]),
+            Color::Ansi16(n) => Ansi::Owned(format!("\x1b[{}m", 90 + n % 8)),
+            Color::Indexed(n) => Ansi::Owned(format!("\x1b[38;5;{}m", n)),
+            Color::Rgb(r, g, b) => Ansi::Owned(format!("\x1b[38;2;{};{};{}m", r, g, b)),
+        }
+    }
+
+    /// Returns the escape sequence setting this color as the background.
+    pub(crate) fn bg_ansi(self) -> Ansi {
+        use ansi::*;
+
+        const BASE: [&str; 8] = [
+            ESC_BLACK_B, ESC_RED_B, ESC_GREEN_B, ESC_YELLOW_B,
+            ESC_BLUE_B, ESC_MAGENTA_B, ESC_CYAN_B, ESC_WHITE_B,
+        ];
+
+        match self {
+            Color::Ansi16(n) if n % 16 < 8 => Ansi::Borrowed(BASE[(n % 8) as usize]),
+            Color::Ansi16(n) => Ansi::Owned(format!("\x1b[{}m", 100 + n % 8)),
+            Color::Indexed(n) => Ansi::Owned(format!("\x1b[48;5;{}m", n)),
+            Color::Rgb(r, g, b) => Ansi::Owned(format!("\x1b[48;2;{};{};{}m", r, g, b)),
+        }
+    }
+}
+
+/// Converts a `Colors` into the matching standard color. Whether it was a
+/// foreground or background color is dropped.
+impl From<Colors> for Color {
+    fn from(color: Colors) -> Self {
+        Color::Ansi16(color as u8 % 8)
+    }
+}
@@ -38,7 +38,8 @@ impl Dimension {
         }
     }
 
-    /// Constructs a new fullscreen `Renderer` (Does not resize).
+    /// Constructs a new fullscreen `Renderer` (Does not resize, see
+    /// `Renderer::fullscreen` and `Renderer::auto_fullscreen`).
     ///
     /// # Returns
     /// `Ok(Renderer)`: A `Renderer` instance.
@@ -47,7 +48,7 @@ impl Dimension {
     /// # Example
     /// ```rust
     /// // Create a fullscreen Renderer.
-    /// let renderer = Renderer::fullscreen()?;
+    /// let renderer = Renderer::new(Dimension::fullscreen()?);
     /// ```
     pub fn fullscreen() -> FtuiResult<Self> {
         let (width, height) = ct::terminal::size()?;
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 pub type GeneratedId = u32;
 
 #[derive(Debug, PartialEq, Eq)]
@@ -17,3 +19,42 @@ impl IdGenerator {
         self.id
     }
 }
+
+/// An optional map from IDs to indices, giving `O(1)` lookups by ID in large
+/// collections. While disabled it does nothing and lookups fall back to a
+/// linear scan.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub(crate) struct IdIndex {
+    map: Option<HashMap<GeneratedId, usize>>,
+}
+
+impl IdIndex {
+    /// Enables the index, building it from the IDs currently in the collection.
+    pub(crate) fn enable(&mut self, ids: impl Iterator<Item = GeneratedId>) {
+        self.map = Some(ids.enumerate().map(|(i, id)| (id, i)).collect());
+    }
+
+    /// Records that `id` is at `index`.
+    pub(crate) fn insert(&mut self, id: GeneratedId, index: usize) {
+        if let Some(map) = &mut self.map {
+            map.insert(id, index);
+        }
+    }
+
+    /// Rebuilds the index after elements were removed or reordered.
+    pub(crate) fn rebuild(&mut self, ids: impl Iterator<Item = GeneratedId>) {
+        if self.map.is_some() {
+            self.enable(ids);
+        }
+    }
+
+    /// Returns the index of `id`, using `linear` when the index is disabled.
+    pub(crate) fn find(
+        &self, id: GeneratedId, linear: impl FnOnce() -> Option<usize>
+    ) -> Option<usize> {
+        match &self.map {
+            Some(map) => map.get(&id).copied(),
+            None => linear(),
+        }
+    }
+}
@@ -1,13 +1,30 @@
-pub(crate) mod ansi;
+pub mod ansi;
 pub(crate) mod id;
 pub(crate) mod number;
 
 mod color;
 pub use color::Colors;
+pub use color::Color;
 
 mod dimension;
 pub use dimension::Dimension;
 
+mod rectangle;
+pub use rectangle::Rectangle;
+
+mod overflow;
+pub use overflow::OverflowPolicy;
+pub(crate) use overflow::truncate;
+pub(crate) use overflow::display_width;
+
+mod align;
+pub use align::VerticalAlign;
+
+mod style;
+pub use style::Style;
+
+mod window;
+pub use window::horizontal_window;
+
 mod traits;
-pub(crate) use traits::Renderable;
 pub(crate) use traits::RenderableMut;
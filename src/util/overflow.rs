@@ -0,0 +1,84 @@
+use std::borrow::Cow;
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::containers::WordWrap;
+use crate::containers::WrapStrategy;
+use crate::error::FtuiError;
+use crate::error::FtuiResult;
+
+/// An `enum` describing what a container does when a component's label is
+/// wider than the `Renderer`.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// Rendering fails with `FtuiError::RendererContainerTooBig`.
+    #[default]
+    Error,
+
+    /// The label is cut to fit and ends with `…`.
+    ///
+    /// `A very long label th…`
+    Truncate,
+
+    /// The label is broken between words onto as many rows as it needs, and
+    /// the components below it move down.
+    ///
+    /// `A very long label that`
+    /// `does not fit`
+    Wrap,
+}
+
+impl OverflowPolicy {
+    /// Fits a `label` that is `len` columns wide into `width` columns, as the
+    /// rows it is rendered on. Only `Wrap` gives more than one row.
+    pub(crate) fn rows<'a>(
+        self, label: &'a str, len: usize, width: usize
+    ) -> FtuiResult<Vec<Cow<'a, str>>> {
+        if len <= width {
+            return Ok(vec![Cow::Borrowed(label)]);
+        }
+
+        match self {
+            OverflowPolicy::Error => Err(FtuiError::RendererContainerTooBig),
+            OverflowPolicy::Truncate => Ok(vec![Cow::Owned(truncate(label, width))]),
+            OverflowPolicy::Wrap =>
+                Ok(WordWrap.wrap(label, width).into_iter().map(Cow::Owned).collect()),
+        }
+    }
+}
+
+/// Returns the number of terminal columns `label` takes, with wide characters
+/// such as CJK taking two columns.
+#[inline]
+pub(crate) fn display_width(label: &str) -> usize {
+    label.width()
+}
+
+/// Cuts `label` down to `width` columns, replacing the last visible grapheme
+/// with `…` when anything was cut.
+pub(crate) fn truncate(label: &str, width: usize) -> String {
+    if display_width(label) <= width {
+        return label.to_string();
+    }
+    if width == 0 {
+        return String::new();
+    }
+
+    let mut truncated = String::new();
+    let mut used = 0;
+
+    for grapheme in label.graphemes(true) {
+        used += display_width(grapheme);
+
+        if used > width - 1 {
+            break;
+        }
+
+        truncated.push_str(grapheme);
+    }
+
+    truncated.push('…');
+    truncated
+}
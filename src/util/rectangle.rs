@@ -0,0 +1,42 @@
+/// A rectangular area of the terminal, described by the position of its
+/// top-left corner and its size in characters.
+///
+/// # Example
+/// ```rust
+/// // A 20 by 10 area starting at column 40, line 0.
+/// let rect = Rectangle::new(40, 0, 20, 10);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rectangle {
+    x: u16,
+    y: u16,
+    width: u16,
+    height: u16,
+}
+
+impl Rectangle {
+    pub fn new(x: u16, y: u16, width: u16, height: u16) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    pub fn x(&self) -> u16 {
+        self.x
+    }
+
+    pub fn y(&self) -> u16 {
+        self.y
+    }
+
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+}
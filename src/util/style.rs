@@ -0,0 +1,118 @@
+use crate::components::TextFlags;
+use crate::util::ansi;
+use crate::util::ansi::Ansi;
+use crate::util::Color;
+
+/// A complete text style: a foreground color, a background color and a set
+/// of attributes (bold, italic, ...). Unlike `TextFlags`, which only allow a
+/// single standard color, a `Style` can set the foreground and background
+/// together, with any `Color`.
+///
+/// # Example
+/// ```rust
+/// // Bold white text on a red background.
+/// let style = Style::new()
+///     .fg(Colors::WhiteFore)
+///     .bg(Colors::RedBack)
+///     .bold();
+///
+/// text.set_style(style);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Style {
+    fg: Option<Color>,
+    bg: Option<Color>,
+    attributes: TextFlags,
+}
+
+impl Style {
+    /// Constructs a `Style` with no colors and no attributes.
+    pub fn new() -> Self {
+        Self {
+            fg: None,
+            bg: None,
+            attributes: TextFlags::empty(),
+        }
+    }
+
+    /// Sets the foreground color, a `Color` or `Colors`.
+    pub fn fg(mut self, color: impl Into<Color>) -> Self {
+        self.fg = Some(color.into());
+        self
+    }
+
+    /// Sets the background color, a `Color` or `Colors`.
+    pub fn bg(mut self, color: impl Into<Color>) -> Self {
+        self.bg = Some(color.into());
+        self
+    }
+
+    pub fn bold(mut self) -> Self {
+        self.attributes |= TextFlags::STYLE_BOLD;
+        self
+    }
+
+    pub fn dim(mut self) -> Self {
+        self.attributes |= TextFlags::STYLE_DIM;
+        self
+    }
+
+    pub fn italic(mut self) -> Self {
+        self.attributes |= TextFlags::STYLE_ITALIC;
+        self
+    }
+
+    pub fn underline(mut self) -> Self {
+        self.attributes |= TextFlags::STYLE_UNDER;
+        self
+    }
+
+    pub fn strikethrough(mut self) -> Self {
+        self.attributes |= TextFlags::STYLE_STRIKE;
+        self
+    }
+
+    pub(crate) fn resolve_ansi(&self) -> Vec<Ansi> {
+        let mut style: Vec<Ansi> = vec![];
+
+        style.extend(self.fg.map(Color::fg_ansi));
+        style.extend(self.bg.map(Color::bg_ansi));
+
+        for (flag, esc) in [
+            (TextFlags::STYLE_BOLD, ansi::ESC_BOLD),
+            (TextFlags::STYLE_DIM, ansi::ESC_DIM),
+            (TextFlags::STYLE_ITALIC, ansi::ESC_ITALIC),
+            (TextFlags::STYLE_UNDER, ansi::ESC_UNDERLINE),
+            (TextFlags::STYLE_STRIKE, ansi::ESC_STRIKETHROUGH),
+        ] {
+            if self.attributes.contains(flag) {
+                style.push(Ansi::Borrowed(esc));
+            }
+        }
+
+        style
+    }
+}
+
+/// Converts the color and style flags of `TextFlags` into a `Style`. The
+/// color becomes the background color if `COLOR_BACK` is set. Alignment
+/// flags are dropped.
+impl From<TextFlags> for Style {
+    fn from(flags: TextFlags) -> Self {
+        let mut style = Style::new();
+
+        match flags.color() {
+            Some(color) if flags.contains(TextFlags::COLOR_BACK) => style.bg = Some(color.into()),
+            color => style.fg = color.map(Color::from),
+        }
+
+        style.attributes = flags.intersection(
+            TextFlags::STYLE_BOLD |
+            TextFlags::STYLE_DIM |
+            TextFlags::STYLE_ITALIC |
+            TextFlags::STYLE_UNDER |
+            TextFlags::STYLE_STRIKE);
+
+        style
+    }
+}
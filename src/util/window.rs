@@ -0,0 +1,63 @@
+use std::ops::Range;
+
+/// Picks which items of a horizontal row (tabs, menu entries, chips, ...)
+/// are visible when the row is wider than `width`, keeping the `active` item
+/// in view. When items are cut off, one column on that side is reserved for
+/// an indicator such as `‹` or `›`.
+///
+/// # Parameters
+/// - `items`: The width of every item, including any spacing around it.
+/// - `width`: The number of columns available.
+/// - `active`: The index of the item that must stay visible.
+///
+/// # Returns
+/// `(Range<usize>, bool, bool)`: The range of visible items, and whether the
+/// left and right indicators should be shown.
+///
+/// # Example
+/// ```rust
+/// // Five 6 column tabs in 20 columns, with the fourth one active.
+/// let (visible, left, right) = horizontal_window(&[6; 5], 20, 3);
+///
+/// assert_eq!(visible, 1..4);
+/// assert!(left && right);
+/// ```
+pub fn horizontal_window(
+    items: &[usize], width: usize, active: usize
+) -> (Range<usize>, bool, bool) {
+    let len = items.len();
+
+    if len == 0 {
+        return (0..0, false, false);
+    }
+
+    let active = active.min(len - 1);
+    let fits = |begin: usize, end: usize| {
+        let indicators = (begin > 0) as usize + (end < len) as usize;
+        items[begin..end].iter().sum::<usize>() + indicators <= width
+    };
+
+    // Show as much as possible from the start of the row.
+    let mut end = 0;
+    while end < len && fits(0, end + 1) {
+        end += 1;
+    }
+
+    if active < end {
+        return (0..end, false, end < len);
+    }
+
+    // Otherwise scroll so the active item is the last one, then fill any
+    // space left on either side.
+    let (mut begin, mut end) = (active, active + 1);
+
+    while begin > 0 && fits(begin - 1, end) {
+        begin -= 1;
+    }
+
+    while end < len && fits(begin, end + 1) {
+        end += 1;
+    }
+
+    (begin..end, begin > 0, end < len)
+}